@@ -31,7 +31,7 @@ where
 
 #[derive(Debug, Parser)]
 struct Args {
-    /// Component name [possible values: latest, nightly]
+    /// Component name [possible values: latest, stable, nightly]
     pub channel: String,
     /// the TOML file name
     pub out_file: String,
@@ -42,6 +42,10 @@ struct Args {
     /// Component name [possible values: latest]
     #[clap(value_parser = parse_key_val::<String, Version>)]
     pub packages: Vec<(String, Version)>,
+    /// Revision index of the channel to record this revision in, eg. 'index.toml'. The file is
+    /// created if it does not exist yet.
+    #[clap(long)]
+    pub index: Option<String>,
 }
 
 fn implicit_table() -> toml_edit::Item {
@@ -107,9 +111,9 @@ fn validate_components(channel: &str, components: &HashMap<String, Version>) ->
                 bail!("Cannot specify versions when building 'nightly' channel")
             }
         }
-        "latest" => {
+        "latest" | "stable" => {
             if !components_exists(components) {
-                bail!("You must specify versions for 'forc' and 'fuel-core' when building 'latest' channel")
+                bail!("You must specify versions for 'forc' and 'fuel-core' when building '{channel}' channel")
             }
         }
         _ => bail!("Invalid channel '{channel}'"),
@@ -248,6 +252,45 @@ fn write_latest_document(
     Ok(())
 }
 
+// Records the revision published on `publish_date` within the revision index of its channel: the
+// hash of the channel TOML and the version of each of its packages. A revision published earlier
+// on the same date is replaced, just like its dated copy of the channel.
+fn record_revision(
+    index_file: &str,
+    publish_date: &str,
+    channel_str: &str,
+    document: &Document,
+) -> Result<()> {
+    let mut index = match fs::read_to_string(index_file) {
+        Ok(s) => s.parse::<Document>()?,
+        Err(_) => Document::new(),
+    };
+
+    let mut hasher = Sha256::new();
+    hasher.update(channel_str.as_bytes());
+
+    let mut pkg = toml_edit::InlineTable::new();
+    if let Some(packages) = document["pkg"].as_table() {
+        for (name, package) in packages.iter() {
+            if let Some(version) = package.get("version").and_then(|v| v.as_str()) {
+                pkg.insert(name, version.into());
+            }
+        }
+    }
+
+    if index.get("revision").is_none() {
+        index["revision"] = implicit_table();
+    }
+    index["revision"][publish_date] = toml_edit::table();
+    index["revision"][publish_date]["hash"] = value(format!("{:x}", hasher.finalize()));
+    index["revision"][publish_date]["pkg"] = value(pkg);
+
+    println!("recording revision '{}' in '{}'", publish_date, index_file);
+    // toml_edit separates tables by a blank line, including the first one.
+    fs::write(index_file, index.to_string().trim_start())?;
+    Ok(())
+}
+
 fn main() -> Result<()> {
     let args = Args::parse();
 
@@ -264,7 +307,10 @@ fn main() -> Result<()> {
 
     match args.channel.as_str() {
         "nightly" => write_nightly_document(&mut document, components)?,
-        "latest" => write_latest_document(&mut document, components, component_versions)?,
+        // 'stable' is built from published releases just like 'latest', only promoted less often.
+        "latest" | "stable" => {
+            write_latest_document(&mut document, components, component_versions)?
+        }
         _ => bail!("Unrecognized channel '{}'", args.channel.as_str()),
     }
 
@@ -278,5 +324,9 @@ fn main() -> Result<()> {
     channel_str.push_str(&document.to_string());
     fs::write(&args.out_file, &channel_str)?;
 
+    if let Some(index_file) = &args.index {
+        record_revision(index_file, &args.publish_date, &channel_str, &document)?;
+    }
+
     Ok(())
 }
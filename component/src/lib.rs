@@ -1,7 +1,7 @@
 use std::collections::HashMap;
 
-use anyhow::{anyhow, Result};
-use serde::Deserialize;
+use anyhow::{anyhow, bail, Result};
+use serde::{Deserialize, Serialize};
 use toml_edit::de;
 
 // Keeping forc since some ways we handle forc is slightly different.
@@ -9,12 +9,28 @@ pub const FORC: &str = "forc";
 pub const FUELUP: &str = "fuelup";
 // forc-client is handled differently - its actual binaries are 'forc-run' and 'forc-deploy'
 pub const FORC_CLIENT: &str = "forc-client";
+// The installation profile used unless another one is selected.
+pub const DEFAULT_PROFILE: &str = "default";
 
 const COMPONENTS_TOML: &str = include_str!("../../components.toml");
 
 #[derive(Debug, Deserialize)]
 pub struct Components {
     pub component: HashMap<String, Component>,
+    #[serde(default)]
+    pub profile: HashMap<String, Profile>,
+}
+
+/// A set of published components to install with a distributable toolchain.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct Profile {
+    pub components: Vec<String>,
+}
+
+impl Profile {
+    pub fn includes(&self, component: &str) -> bool {
+        self.components.iter().any(|c| c == component)
+    }
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -48,7 +64,7 @@ impl Component {
             .component
             .get(name)
             .ok_or_else(|| anyhow!("component with name '{}' does not exist", name))
-            .map(|c| c.clone())
+            .cloned()
     }
 
     pub fn is_default_forc_plugin(name: &str) -> bool {
@@ -125,7 +141,8 @@ impl Components {
                     .get(c)
                     .expect("Failed to parse components.toml")
             })
-            .filter_map(|c| c.is_plugin.is_none().then(|| c.clone()))
+            .filter(|c| c.is_plugin.is_none())
+            .cloned()
             .collect();
 
         main_components.sort_by_key(|c| c.name.clone());
@@ -162,12 +179,31 @@ impl Components {
         let mut executables = vec![];
 
         for plugin in plugins.iter() {
-            executables.extend(plugin.executables.clone().into_iter())
+            executables.extend(plugin.executables.clone())
         }
 
         Ok(executables)
     }
 
+    pub fn profile(name: &str) -> Result<Profile> {
+        let components = Self::from_toml(COMPONENTS_TOML)?;
+        match components.profile.get(name) {
+            Some(profile) => Ok(profile.clone()),
+            None => bail!(
+                "Unknown profile '{}' [possible values: {}]",
+                name,
+                Self::profile_names()?.join(", ")
+            ),
+        }
+    }
+
+    pub fn profile_names() -> Result<Vec<String>> {
+        let components = Self::from_toml(COMPONENTS_TOML)?;
+        let mut names: Vec<String> = components.profile.into_keys().collect();
+        names.sort();
+        Ok(names)
+    }
+
     pub fn is_distributed_by_forc(plugin_name: &str) -> bool {
         let components = Self::from_toml(COMPONENTS_TOML).expect("Failed to parse components toml");
         if let Some(forc) = components.component.get(FORC) {
@@ -225,6 +261,27 @@ targets = ["linux_amd64", "linux_arm64", "darwin_amd64", "darwin_arm64"]
         Ok(())
     }
 
+    #[test]
+    fn test_profiles() -> Result<()> {
+        let publishables: Vec<String> = Components::collect_publishables()?
+            .into_iter()
+            .map(|c| c.name)
+            .collect();
+        for name in Components::profile_names()? {
+            for component in Components::profile(&name)?.components {
+                assert!(publishables.contains(&component), "{name}: {component}");
+            }
+        }
+
+        let complete = Components::profile("complete")?;
+        assert!(publishables.iter().all(|c| complete.includes(c)));
+        // The default profile installs what a plain install did before profiles existed.
+        let default = Components::profile(DEFAULT_PROFILE)?;
+        assert!(publishables.iter().all(|c| default.includes(c)));
+        assert!(Components::profile("everything").is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_collect_plugins() {
         assert!(Components::collect_plugins().is_ok());
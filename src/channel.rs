@@ -1,14 +1,14 @@
 use crate::{
+    channel_index::ChannelIndex,
     constants::{
-        CHANNEL_BETA_1_FILE_NAME, CHANNEL_BETA_2_FILE_NAME, CHANNEL_BETA_3_FILE_NAME,
-        CHANNEL_LATEST_FILE_NAME, CHANNEL_NIGHTLY_FILE_NAME, DATE_FORMAT_URL_FRIENDLY,
-        FUELUP_GH_PAGES,
+        CHANNEL_LATEST_FILE_NAME, CHANNEL_NIGHTLY_FILE_NAME, CHANNEL_STABLE_FILE_NAME,
+        DATE_FORMAT_URL_FRIENDLY, FUELUP_GH_PAGES,
     },
     download::{download, DownloadCfg},
     toolchain::{DistToolchainDescription, DistToolchainName},
 };
 use anyhow::{bail, Result};
-use component::Components;
+use component::{Components, Profile};
 use semver::Version;
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
@@ -19,12 +19,9 @@ use tracing::warn;
 
 pub const LATEST: &str = "latest";
 pub const STABLE: &str = "stable";
-pub const BETA_1: &str = "beta-1";
-pub const BETA_2: &str = "beta-2";
-pub const BETA_3: &str = "beta-3";
 pub const NIGHTLY: &str = "nightly";
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
 pub struct HashedBinary {
     pub url: String,
     pub hash: String,
@@ -32,19 +29,19 @@ pub struct HashedBinary {
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct Channel {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub published_by: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub date: Option<String>,
     pub pkg: BTreeMap<String, Package>,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
 pub struct Package {
     pub target: BTreeMap<String, HashedBinary>,
     pub version: Version,
 }
 
-pub fn is_beta_toolchain(name: &str) -> bool {
-    name == BETA_1 || name == BETA_2 || name == BETA_3
-}
-
 fn format_nightly_url(date: &Date) -> Result<String> {
     Ok(format!(
         "channels/nightly/{}",
@@ -54,7 +51,7 @@ fn format_nightly_url(date: &Date) -> Result<String> {
 
 fn construct_channel_url(desc: &DistToolchainDescription) -> Result<String> {
     let mut url = FUELUP_GH_PAGES.to_owned();
-    match desc.name {
+    match &desc.name {
         DistToolchainName::Latest => {
             if let Some(date) = desc.date {
                 url.push_str(&format!("channels/latest/channel-fuel-latest-{date}.toml"))
@@ -63,6 +60,15 @@ fn construct_channel_url(desc: &DistToolchainDescription) -> Result<String> {
             }
         }
 
+        // Like 'latest', 'stable' is republished as a whole, with each revision kept by date.
+        DistToolchainName::Stable => {
+            if let Some(date) = desc.date {
+                url.push_str(&format!("channels/stable/channel-fuel-stable-{date}.toml"))
+            } else {
+                url.push_str(CHANNEL_STABLE_FILE_NAME)
+            }
+        }
+
         DistToolchainName::Nightly => {
             if let Some(date) = desc.date {
                 url.push_str(&format_nightly_url(&date)?);
@@ -70,9 +76,10 @@ fn construct_channel_url(desc: &DistToolchainDescription) -> Result<String> {
             }
             url.push_str(CHANNEL_NIGHTLY_FILE_NAME)
         }
-        DistToolchainName::Beta1 => url.push_str(CHANNEL_BETA_1_FILE_NAME),
-        DistToolchainName::Beta2 => url.push_str(CHANNEL_BETA_2_FILE_NAME),
-        DistToolchainName::Beta3 => url.push_str(CHANNEL_BETA_3_FILE_NAME),
+        DistToolchainName::Named(name) => match ChannelIndex::load().get(name) {
+            Some(channel) => url.push_str(&channel.file_name(name)),
+            None => bail!("'{}' is not a known channel", name),
+        },
     };
 
     Ok(url)
@@ -80,6 +87,9 @@ fn construct_channel_url(desc: &DistToolchainDescription) -> Result<String> {
 
 impl Channel {
     /// The returned `String` is a sha256 hash of the downloaded toolchain TOML bytes.
+    ///
+    /// A dated channel must have been published on its date; see
+    /// `DistToolchainDescription::resolve_revision` for the channel as it was on that date.
     pub fn from_dist_channel(desc: &DistToolchainDescription) -> Result<(Self, String)> {
         let channel_url = construct_channel_url(desc)?;
 
@@ -98,11 +108,14 @@ impl Channel {
         Ok(channel)
     }
 
-    pub fn build_download_configs(self) -> Vec<DownloadCfg> {
+    /// Download configs for the packages of the channel included in `profile`.
+    pub fn build_download_configs(self, profile: &Profile) -> Vec<DownloadCfg> {
         let mut cfgs = self
             .pkg
             .into_iter()
-            .filter(|(component_name, _)| Components::contains_published(component_name))
+            .filter(|(component_name, _)| {
+                Components::contains_published(component_name) && profile.includes(component_name)
+            })
             .map(|(name, package)| {
                 DownloadCfg::from_package(&name, package).map_err(|_| {
                     warn!(
@@ -123,6 +136,7 @@ If this component should be downloadable, try running `fuelup self update` and r
 mod tests {
     use super::*;
     use crate::{download::DownloadCfg, file::read_file};
+    use std::str::FromStr;
 
     #[test]
     fn channel_from_toml() {
@@ -132,6 +146,10 @@ mod tests {
         let channel_file = read_file("channel-fuel-latest-example", &channel_path).unwrap();
         let channel = Channel::from_toml(&channel_file).unwrap();
 
+        assert_eq!(
+            channel.published_by.as_deref(),
+            Some("https://github.com/FuelLabs/fuelup/actions/runs/12345")
+        );
         assert_eq!(channel.pkg.keys().len(), 2);
         assert!(channel.pkg.contains_key("forc"));
         assert_eq!(
@@ -222,12 +240,36 @@ mod tests {
         let channel_file = read_file("channel-fuel-latest-example", &channel_path).unwrap();
         let channel = Channel::from_toml(&channel_file).unwrap();
 
-        let cfgs: Vec<DownloadCfg> = channel.build_download_configs();
+        let cfgs: Vec<DownloadCfg> =
+            channel.build_download_configs(&Components::profile("complete").unwrap());
 
         assert_eq!(cfgs.len(), 2);
         assert_eq!(cfgs[0].name, "forc");
         assert_eq!(cfgs[0].version, Version::parse("0.17.0").unwrap());
         assert_eq!(cfgs[1].name, "fuel-core");
         assert_eq!(cfgs[1].version, Version::parse("0.9.4").unwrap());
+
+        let profile = Profile {
+            components: vec!["fuel-core".to_string()],
+        };
+        let channel = Channel::from_toml(&channel_file).unwrap();
+        let cfgs: Vec<DownloadCfg> = channel.build_download_configs(&profile);
+        assert_eq!(cfgs.len(), 1);
+        assert_eq!(cfgs[0].name, "fuel-core");
+    }
+
+    #[test]
+    fn stable_channel_url() {
+        let desc = DistToolchainDescription::from_str(STABLE).unwrap();
+        assert_eq!(
+            construct_channel_url(&desc).unwrap(),
+            format!("{FUELUP_GH_PAGES}channel-fuel-stable.toml")
+        );
+
+        let desc = DistToolchainDescription::from_str("stable-2023-02-01").unwrap();
+        assert_eq!(
+            construct_channel_url(&desc).unwrap(),
+            format!("{FUELUP_GH_PAGES}channels/stable/channel-fuel-stable-2023-02-01.toml")
+        );
     }
 }
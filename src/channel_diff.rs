@@ -0,0 +1,291 @@
+use anyhow::Result;
+use semver::Version;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::fs;
+use tracing::warn;
+
+use crate::{
+    channel::{Channel, Package},
+    config::Config,
+    revision_index::RevisionIndex,
+    toolchain::{DistToolchainDescription, DistToolchainName, Toolchain},
+};
+
+// How a package differs between two channels. Targets are only compared when both sides know
+// them, eg. not for a toolchain whose channel could not be recovered.
+#[derive(Debug, PartialEq, Eq)]
+pub struct PackageDiff {
+    pub name: String,
+    pub from: Option<Version>,
+    pub to: Option<Version>,
+    pub added_targets: Vec<String>,
+    pub removed_targets: Vec<String>,
+    // Targets whose binary changed without a change of version, ie. republished.
+    pub rebuilt_targets: Vec<String>,
+}
+
+#[derive(Debug, Default)]
+pub struct ChannelDiff {
+    pub packages: Vec<PackageDiff>,
+}
+
+impl ChannelDiff {
+    pub fn new(from: &BTreeMap<String, Package>, to: &BTreeMap<String, Package>) -> Self {
+        let names: BTreeSet<&String> = from.keys().chain(to.keys()).collect();
+        let packages = names
+            .into_iter()
+            .filter_map(|name| PackageDiff::new(name, from.get(name), to.get(name)))
+            .collect();
+        Self { packages }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.packages.is_empty()
+    }
+
+    // The packages of an installed distributable toolchain. Its exact channel is recovered from
+    // the hash recorded when it was installed, falling back to the versions of its components.
+    // `published` is the channel of the toolchain as published now, with its hash, if already
+    // fetched.
+    pub fn installed_packages(
+        description: &DistToolchainDescription,
+        published: Option<&(Channel, String)>,
+    ) -> Result<BTreeMap<String, Package>> {
+        let toolchain = Toolchain::from_path(&description.to_string());
+        let config = Config::from_env()?;
+        let installed_hash = fs::read_to_string(config.hashes_dir().join(&toolchain.name)).ok();
+
+        if let Some(hash) = installed_hash {
+            match installed_channel(description, &hash, published) {
+                Ok(Some(channel)) => return Ok(channel),
+                Ok(None) => {}
+                Err(e) => warn!(
+                    "warning: could not recover the channel '{}' was installed from: {}",
+                    toolchain.name, e
+                ),
+            }
+        }
+
+        Ok(toolchain
+            .component_versions()?
+            .into_iter()
+            .filter_map(|(name, version)| {
+                version.map(|version| {
+                    let package = Package {
+                        target: BTreeMap::new(),
+                        version,
+                    };
+                    (name, package)
+                })
+            })
+            .collect())
+    }
+}
+
+// The packages of the channel with the given hash, either as published now or as one of its
+// revisions.
+fn installed_channel(
+    description: &DistToolchainDescription,
+    hash: &str,
+    published: Option<&(Channel, String)>,
+) -> Result<Option<BTreeMap<String, Package>>> {
+    let fetched;
+    let (published, published_hash) = match published {
+        Some(published) => published,
+        None => {
+            fetched = Channel::from_dist_channel(description)?;
+            &fetched
+        }
+    };
+    if published_hash == hash {
+        return Ok(Some(published.pkg.clone()));
+    }
+
+    // Only channels published by date keep their previous revisions.
+    let has_revisions =
+        description.date.is_none() && !matches!(description.name, DistToolchainName::Named(_));
+    if !has_revisions {
+        return Ok(None);
+    }
+    let index = RevisionIndex::load(&description.name.to_string(), false)?;
+    let Some(date) = index
+        .revision
+        .iter()
+        .find(|(_, revision)| revision.hash == hash)
+        .map(|(date, _)| date)
+    else {
+        return Ok(None);
+    };
+
+    let revision: DistToolchainDescription = format!("{}-{}", description.name, date).parse()?;
+    Ok(Some(Channel::from_dist_channel(&revision)?.0.pkg))
+}
+
+impl PackageDiff {
+    fn new(name: &str, from: Option<&Package>, to: Option<&Package>) -> Option<Self> {
+        let mut diff = Self {
+            name: name.to_string(),
+            from: from.map(|p| p.version.clone()),
+            to: to.map(|p| p.version.clone()),
+            added_targets: vec![],
+            removed_targets: vec![],
+            rebuilt_targets: vec![],
+        };
+
+        if let (Some(from), Some(to)) = (from, to) {
+            if !from.target.is_empty() && !to.target.is_empty() {
+                for (target, binary) in &to.target {
+                    match from.target.get(target) {
+                        None => diff.added_targets.push(target.clone()),
+                        Some(previous)
+                            if previous.hash != binary.hash && from.version == to.version =>
+                        {
+                            diff.rebuilt_targets.push(target.clone())
+                        }
+                        Some(_) => {}
+                    }
+                }
+                diff.removed_targets = from
+                    .target
+                    .keys()
+                    .filter(|target| !to.target.contains_key(*target))
+                    .cloned()
+                    .collect();
+            }
+
+            if from.version == to.version
+                && diff.added_targets.is_empty()
+                && diff.removed_targets.is_empty()
+                && diff.rebuilt_targets.is_empty()
+            {
+                return None;
+            }
+        }
+
+        Some(diff)
+    }
+}
+
+impl fmt::Display for PackageDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.from, &self.to) {
+            (None, Some(to)) => write!(f, "+ {} {}", self.name, to)?,
+            (Some(from), None) => write!(f, "- {} {}", self.name, from)?,
+            (Some(from), Some(to)) if from != to => {
+                write!(f, "~ {} {} -> {}", self.name, from, to)?
+            }
+            (Some(version), Some(_)) => write!(f, "~ {} {}", self.name, version)?,
+            (None, None) => write!(f, "{}", self.name)?,
+        }
+
+        let targets: Vec<String> = [
+            ("added", &self.added_targets),
+            ("removed", &self.removed_targets),
+            ("rebuilt", &self.rebuilt_targets),
+        ]
+        .iter()
+        .filter(|(_, targets)| !targets.is_empty())
+        .map(|(change, targets)| format!("{change}: {}", targets.join(", ")))
+        .collect();
+        if !targets.is_empty() {
+            write!(f, " (targets {})", targets.join("; "))?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for ChannelDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for package in &self.packages {
+            writeln!(f, "  {package}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::channel::HashedBinary;
+
+    fn package(version: &str, targets: &[(&str, &str)]) -> Package {
+        Package {
+            version: Version::parse(version).unwrap(),
+            target: targets
+                .iter()
+                .map(|(target, hash)| {
+                    let binary = HashedBinary {
+                        url: format!("https://example.com/{target}.tar.gz"),
+                        hash: hash.to_string(),
+                    };
+                    (target.to_string(), binary)
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn channel_diff() {
+        let from = BTreeMap::from([
+            (
+                "forc".to_string(),
+                package("0.35.0", &[("linux_amd64", "a"), ("darwin_amd64", "b")]),
+            ),
+            (
+                "forc-explore".to_string(),
+                package("0.28.1", &[("linux_amd64", "c")]),
+            ),
+            (
+                "fuel-core".to_string(),
+                package("0.17.1", &[("x86_64-unknown-linux-gnu", "d")]),
+            ),
+            (
+                "forc-wallet".to_string(),
+                package("0.2.0", &[("x86_64-unknown-linux-gnu", "e")]),
+            ),
+        ]);
+        let to = BTreeMap::from([
+            (
+                "forc".to_string(),
+                package("0.35.1", &[("linux_amd64", "f"), ("linux_arm64", "g")]),
+            ),
+            (
+                "fuel-core".to_string(),
+                package(
+                    "0.17.1",
+                    &[
+                        ("x86_64-unknown-linux-gnu", "h"),
+                        ("x86_64-apple-darwin", "i"),
+                    ],
+                ),
+            ),
+            (
+                "forc-wallet".to_string(),
+                package("0.2.0", &[("x86_64-unknown-linux-gnu", "e")]),
+            ),
+            ("forc-fmt".to_string(), package("0.35.1", &[])),
+        ]);
+
+        let diff = ChannelDiff::new(&from, &to);
+        assert_eq!(
+            diff.to_string(),
+            "  ~ forc 0.35.0 -> 0.35.1 (targets added: linux_arm64; removed: darwin_amd64)
+  - forc-explore 0.28.1
+  + forc-fmt 0.35.1
+  ~ fuel-core 0.17.1 (targets added: x86_64-apple-darwin; rebuilt: x86_64-unknown-linux-gnu)
+"
+        );
+        assert!(ChannelDiff::new(&to, &to).is_empty());
+    }
+
+    #[test]
+    fn channel_diff_unknown_targets() {
+        let installed = BTreeMap::from([("forc".to_string(), package("0.35.0", &[]))]);
+        let published = BTreeMap::from([(
+            "forc".to_string(),
+            package("0.35.0", &[("linux_amd64", "a")]),
+        )]);
+        assert!(ChannelDiff::new(&installed, &published).is_empty());
+    }
+}
@@ -0,0 +1,195 @@
+use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::io::Read;
+use toml_edit::de;
+
+use crate::{
+    channel::{LATEST, NIGHTLY, STABLE},
+    constants::{CHANNEL_INDEX_FILE_NAME, FUELUP_GH_PAGES},
+    file,
+    path::{channel_index_file, ensure_dir_exists, fuelup_dir},
+};
+
+const CHANNELS_TOML: &str = include_str!("../channels.toml");
+
+// Channels that are published by date. Their layout on gh-pages is known to fuelup, so they are
+// not part of the index.
+pub const DATED_CHANNELS: &[&str] = &[LATEST, NIGHTLY, STABLE];
+
+// The set of named channels, eg. 'beta-3'. The copy embedded at build time is extended with the
+// last published index fetched into the fuelup directory, so that channels published after a
+// release of fuelup can be installed without updating fuelup.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct ChannelIndex {
+    #[serde(default)]
+    pub channel: BTreeMap<String, NamedChannel>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct NamedChannel {
+    // File name of the channel at the root of gh-pages, if not 'channel-fuel-<name>.toml'.
+    pub file: Option<String>,
+    pub description: Option<String>,
+}
+
+impl ChannelIndex {
+    pub fn from_toml(toml: &str) -> Result<Self> {
+        let index: ChannelIndex = de::from_str(toml)?;
+        if let Some(name) = index
+            .channel
+            .keys()
+            .find(|name| DATED_CHANNELS.contains(&name.as_str()))
+        {
+            bail!("'{name}' is published by date and cannot be declared as a named channel");
+        }
+        Ok(index)
+    }
+
+    pub fn embedded() -> Self {
+        Self::from_toml(CHANNELS_TOML).expect("the embedded channels.toml is valid")
+    }
+
+    // The embedded index, extended by the last fetched one. A fetched index that can't be read is
+    // ignored, since it may be refreshed at any time.
+    pub fn load() -> Self {
+        let mut index = Self::embedded();
+        if let Ok(toml) = file::read_file(CHANNEL_INDEX_FILE_NAME, &channel_index_file()) {
+            if let Ok(fetched) = Self::from_toml(&toml) {
+                index.channel.extend(fetched.channel);
+            }
+        }
+        index
+    }
+
+    // Fetches the published index into the fuelup directory and returns the updated index.
+    pub fn refresh() -> Result<Self> {
+        let url = format!("{FUELUP_GH_PAGES}{CHANNEL_INDEX_FILE_NAME}");
+        let handle = ureq::builder().user_agent("fuelup").build();
+        let mut toml = String::new();
+        handle
+            .get(&url)
+            .call()?
+            .into_reader()
+            .read_to_string(&mut toml)?;
+
+        // Validate before replacing the previous copy.
+        Self::from_toml(&toml)?;
+        ensure_dir_exists(&fuelup_dir())?;
+        file::write_file(&channel_index_file(), &toml)?;
+        Ok(Self::load())
+    }
+
+    pub fn get(&self, name: &str) -> Option<&NamedChannel> {
+        self.channel.get(name)
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.channel.contains_key(name)
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.channel.keys().map(String::as_str)
+    }
+
+    // The names of every distributable channel, dated ones first.
+    pub fn channel_names(&self) -> Vec<String> {
+        DATED_CHANNELS
+            .iter()
+            .copied()
+            .chain(self.names())
+            .map(str::to_string)
+            .collect()
+    }
+
+    // Splits a toolchain description like 'beta-3-x86_64-unknown-linux-gnu' into its channel name
+    // and the remaining metadata. Since named channels may contain '-' themselves, the longest
+    // matching name wins.
+    pub fn split_channel<'a>(&self, s: &'a str) -> Option<(&'a str, &'a str)> {
+        DATED_CHANNELS
+            .iter()
+            .copied()
+            .chain(self.names())
+            .filter(|name| {
+                s.strip_prefix(name)
+                    .is_some_and(|rest| rest.is_empty() || rest.starts_with('-'))
+            })
+            .max_by_key(|name| name.len())
+            .map(|name| (&s[..name.len()], s[name.len()..].trim_start_matches('-')))
+    }
+
+    // Refreshes the index if `toolchain` does not name a known channel, in case it has been
+    // published since. Failing to fetch the index is not an error: `toolchain` is then reported
+    // as unknown by whatever parses it next.
+    pub fn refresh_if_unknown(toolchain: &str) {
+        if Self::load().split_channel(toolchain).is_none() {
+            let _ = Self::refresh();
+        }
+    }
+}
+
+impl NamedChannel {
+    pub fn file_name(&self, name: &str) -> String {
+        self.file
+            .clone()
+            .unwrap_or_else(|| format!("channel-fuel-{name}.toml"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn embedded_index() {
+        let index = ChannelIndex::embedded();
+        assert_eq!(
+            index.channel_names(),
+            ["latest", "nightly", "stable", "beta-1", "beta-2", "beta-3"]
+        );
+        assert_eq!(
+            index.get("beta-3").unwrap().file_name("beta-3"),
+            "channel-fuel-beta-3.toml"
+        );
+    }
+
+    #[test]
+    fn split_channel() {
+        let index = ChannelIndex::from_toml(
+            r#"
+[channel.testnet]
+
+[channel.testnet-2]
+file = "channel-fuel-testnet-v2.toml"
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(index.split_channel("latest"), Some(("latest", "")));
+        assert_eq!(
+            index.split_channel("nightly-2023-01-09"),
+            Some(("nightly", "2023-01-09"))
+        );
+        assert_eq!(index.split_channel("testnet"), Some(("testnet", "")));
+        assert_eq!(index.split_channel("testnet-2"), Some(("testnet-2", "")));
+        assert_eq!(
+            index.split_channel("testnet-x86_64-unknown-linux-gnu"),
+            Some(("testnet", "x86_64-unknown-linux-gnu"))
+        );
+        assert_eq!(index.split_channel("testnets"), None);
+        assert_eq!(index.split_channel("beta-4"), None);
+        assert_eq!(
+            index.get("testnet").unwrap().file_name("testnet"),
+            "channel-fuel-testnet.toml"
+        );
+        assert_eq!(
+            index.get("testnet-2").unwrap().file_name("testnet-2"),
+            "channel-fuel-testnet-v2.toml"
+        );
+    }
+
+    #[test]
+    fn dated_channel_in_index() {
+        assert!(ChannelIndex::from_toml("[channel.nightly]\n").is_err());
+    }
+}
@@ -0,0 +1,30 @@
+use anyhow::Result;
+use clap::Parser;
+use std::ffi::OsString;
+use time::Date;
+
+use crate::commands::toolchain::parse_date;
+use crate::ops::fuelup_bisect;
+
+#[derive(Debug, Parser)]
+#[clap(trailing_var_arg = true)]
+pub struct BisectCommand {
+    /// Channel to bisect the revisions of [possible values: latest, nightly, stable]
+    #[clap(long, default_value = "nightly")]
+    pub channel: String,
+    /// Date (YYYY-MM-DD) on which the channel was known to be good
+    #[clap(long, value_parser = parse_date)]
+    pub good: Date,
+    /// Date (YYYY-MM-DD) on which the channel was known to be bad
+    #[clap(long, value_parser = parse_date)]
+    pub bad: Date,
+    /// Command to run against each revision; a revision is good if the command succeeds
+    pub command: OsString,
+    /// Arguments passed to the command
+    #[clap(multiple_values = true, allow_hyphen_values = true)]
+    pub args: Vec<OsString>,
+}
+
+pub fn exec(command: BisectCommand) -> Result<()> {
+    fuelup_bisect::bisect(command)
+}
@@ -0,0 +1,41 @@
+use anyhow::Result;
+use clap::Parser;
+
+use crate::ops::fuelup_channel::{diff::diff, show::show};
+
+#[derive(Debug, Parser)]
+pub enum ChannelCommand {
+    /// Show the packages published in a channel, without installing it
+    Show(ShowCommand),
+    /// Show which packages changed between two channels, or an installed toolchain and a channel
+    Diff(DiffCommand),
+}
+
+#[derive(Debug, Parser)]
+pub struct ShowCommand {
+    /// Channel to show, eg. latest, nightly-2023-01-15 or beta-3
+    pub channel: String,
+    /// Print the channel as JSON
+    #[clap(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Parser)]
+pub struct DiffCommand {
+    /// Channel to compare from, eg. latest-2023-01-09
+    pub from: String,
+    /// Channel to compare to [default: the channel of <FROM> as published now, eg. latest]
+    pub to: Option<String>,
+    /// Compare from the installed toolchain of <FROM> instead of its published channel
+    #[clap(long)]
+    pub installed: bool,
+}
+
+pub fn exec(command: ChannelCommand) -> Result<()> {
+    match command {
+        ChannelCommand::Show(command) => show(command)?,
+        ChannelCommand::Diff(command) => diff(command)?,
+    };
+
+    Ok(())
+}
@@ -0,0 +1,15 @@
+use anyhow::Result;
+use clap::Parser;
+
+use crate::ops::fuelup_lock;
+
+#[derive(Debug, Parser)]
+pub struct LockCommand {
+    /// Resolve every component again, instead of keeping what is already locked
+    #[clap(long)]
+    pub update: bool,
+}
+
+pub fn exec(command: LockCommand) -> Result<()> {
+    fuelup_lock::lock(command)
+}
@@ -1,8 +1,15 @@
+pub mod bisect;
+pub mod channel;
 pub mod check;
 pub mod completions;
 pub mod component;
 pub mod default;
 pub mod fuelup;
+pub mod lock;
+pub mod overrides;
+pub mod run;
+pub mod set;
 pub mod show;
 pub mod toolchain;
 pub mod update;
+pub mod which;
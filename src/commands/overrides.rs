@@ -0,0 +1,62 @@
+use anyhow::Result;
+use clap::Parser;
+use std::path::PathBuf;
+
+use crate::ops::fuelup_override::{check::check, pin::pin, set_channel::set_channel, unpin::unpin};
+
+#[derive(Debug, Parser)]
+pub enum OverrideCommand {
+    /// Validate the fuel-toolchain.toml of a project
+    Check(CheckCommand),
+    /// Set the channel of the fuel-toolchain.toml, creating it if needed
+    SetChannel(SetChannelCommand),
+    /// Pin a component to a version or version requirement in the fuel-toolchain.toml
+    Pin(PinCommand),
+    /// Remove a pinned component from the fuel-toolchain.toml
+    Unpin(UnpinCommand),
+}
+
+#[derive(Debug, Parser)]
+pub struct CheckCommand {
+    /// Check the fuel-toolchain.toml of this project directory, instead of the current one
+    #[clap(long)]
+    pub path: Option<PathBuf>,
+}
+
+#[derive(Debug, Parser)]
+pub struct SetChannelCommand {
+    /// Channel to use [possible values: latest-YYYY-MM-DD, nightly-YYYY-MM-DD, stable-YYYY-MM-DD, or a named channel like beta-3]
+    pub channel: String,
+    /// Edit the fuel-toolchain.toml of this project directory, instead of the current one
+    #[clap(long)]
+    pub path: Option<PathBuf>,
+}
+
+#[derive(Debug, Parser)]
+pub struct PinCommand {
+    /// Component to pin, eg. forc@0.35.0 or fuel-core@^0.17
+    pub versioned_component: String,
+    /// Edit the fuel-toolchain.toml of this project directory, instead of the current one
+    #[clap(long)]
+    pub path: Option<PathBuf>,
+}
+
+#[derive(Debug, Parser)]
+pub struct UnpinCommand {
+    /// Component to unpin, eg. fuel-core
+    pub component: String,
+    /// Edit the fuel-toolchain.toml of this project directory, instead of the current one
+    #[clap(long)]
+    pub path: Option<PathBuf>,
+}
+
+pub fn exec(command: OverrideCommand) -> Result<()> {
+    match command {
+        OverrideCommand::Check(command) => check(command)?,
+        OverrideCommand::SetChannel(command) => set_channel(command)?,
+        OverrideCommand::Pin(command) => pin(command)?,
+        OverrideCommand::Unpin(command) => unpin(command)?,
+    };
+
+    Ok(())
+}
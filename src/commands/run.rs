@@ -0,0 +1,21 @@
+use anyhow::Result;
+use clap::Parser;
+use std::ffi::OsString;
+
+use crate::ops::fuelup_run;
+
+#[derive(Debug, Parser)]
+#[clap(trailing_var_arg = true)]
+pub struct RunCommand {
+    /// Toolchain name [possible values: latest, stable, nightly, <custom toolchain>]
+    pub toolchain: String,
+    /// Command to run with the toolchain
+    pub command: OsString,
+    /// Arguments passed to the command
+    #[clap(multiple_values = true, allow_hyphen_values = true)]
+    pub args: Vec<OsString>,
+}
+
+pub fn exec(command: RunCommand) -> Result<()> {
+    fuelup_run::run(command)
+}
@@ -0,0 +1,32 @@
+use anyhow::Result;
+use clap::Parser;
+
+use crate::ops::fuelup_set;
+use crate::settings::AutoInstall;
+
+#[derive(Debug, Parser)]
+pub enum SetCommand {
+    /// Set whether proxies install missing toolchains and components before running them
+    AutoInstall(AutoInstallCommand),
+    /// Set the profile of components installed with distributable toolchains
+    Profile(ProfileCommand),
+}
+
+#[derive(Debug, Parser)]
+pub struct AutoInstallCommand {
+    /// Auto-install policy [possible values: always, prompt, never]. Prints the current policy if omitted.
+    pub policy: Option<AutoInstall>,
+}
+
+#[derive(Debug, Parser)]
+pub struct ProfileCommand {
+    /// Profile [possible values: minimal, default, complete]. Prints the current profile if omitted.
+    pub profile: Option<String>,
+}
+
+pub fn exec(command: SetCommand) -> Result<()> {
+    match command {
+        SetCommand::AutoInstall(command) => fuelup_set::auto_install(command.policy),
+        SetCommand::Profile(command) => fuelup_set::profile(command.profile),
+    }
+}
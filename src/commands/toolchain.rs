@@ -1,12 +1,17 @@
-use anyhow::{bail, Result};
+use anyhow::{anyhow, bail, Result};
 use clap::Parser;
+use std::path::PathBuf;
+use time::Date;
 
+use crate::constants::DATE_FORMAT;
+use crate::ops::fuelup_toolchain::export::export;
 use crate::ops::fuelup_toolchain::install::install;
 use crate::ops::fuelup_toolchain::list_revisions::list_revisions;
 use crate::ops::fuelup_toolchain::new::new;
+use crate::ops::fuelup_toolchain::search::search;
 use crate::ops::fuelup_toolchain::uninstall::uninstall;
 use crate::target_triple::TargetTriple;
-use crate::toolchain::RESERVED_TOOLCHAIN_NAMES;
+use crate::toolchain::reserved_toolchain_names;
 
 #[derive(Debug, Parser)]
 pub enum ToolchainCommand {
@@ -16,14 +21,41 @@ pub enum ToolchainCommand {
     New(NewCommand),
     /// Uninstall a toolchain
     Uninstall(UninstallCommand),
-    /// Fetch the list of published `latest` toolchains, starting from the most recent
+    /// List the published revisions of a channel along with their components, starting from the
+    /// most recent
     ListRevisions(ListRevisionsCommand),
+    /// Write a fuel-toolchain.toml reproducing an installed toolchain
+    Export(ExportCommand),
+    /// Find the revisions of a channel containing the given component versions
+    Search(SearchCommand),
 }
 
 #[derive(Debug, Parser)]
 pub struct InstallCommand {
-    /// Toolchain name [possible values: latest, beta-1, beta-2, beta-3, nightly]
-    pub name: String,
+    /// Toolchain name [possible values: latest, stable, nightly, or a named channel like beta-3]
+    #[clap(required_unless_present = "from-file")]
+    pub name: Option<String>,
+    /// Install the toolchain and every component declared by the nearest fuel-toolchain.toml
+    #[clap(long, conflicts_with = "name")]
+    pub from_file: bool,
+    /// Install the channel as it was on the given date (YYYY-MM-DD), ie. its most recent revision
+    /// published on or before it
+    #[clap(long, value_parser = parse_date, conflicts_with = "from-file")]
+    pub as_of: Option<Date>,
+    /// Components of the channel to install [possible values: minimal, default, complete]
+    /// [default: the profile set with 'fuelup set profile']
+    #[clap(long)]
+    pub profile: Option<String>,
+    /// Install only the given component of the channel, instead of a profile. May be repeated.
+    #[clap(
+        long = "component",
+        value_name = "COMPONENT",
+        conflicts_with_all = &["profile", "exclude"]
+    )]
+    pub components: Vec<String>,
+    /// Install the profile without the given component. May be repeated.
+    #[clap(long, value_name = "COMPONENT")]
+    pub exclude: Vec<String>,
 }
 
 #[derive(Debug, Parser)]
@@ -40,7 +72,51 @@ pub struct UninstallCommand {
 }
 
 #[derive(Debug, Parser)]
-pub struct ListRevisionsCommand {}
+pub struct ListRevisionsCommand {
+    /// Channel to list the revisions of [possible values: latest, nightly, stable]
+    #[clap(default_value = "latest")]
+    pub channel: String,
+    /// Only list revisions published on or after this date (YYYY-MM-DD)
+    #[clap(long, value_parser = parse_date)]
+    pub since: Option<Date>,
+    /// Only list revisions published on or before this date (YYYY-MM-DD)
+    #[clap(long, value_parser = parse_date)]
+    pub until: Option<Date>,
+    /// List the revisions fetched last instead of fetching them
+    #[clap(long)]
+    pub offline: bool,
+}
+
+#[derive(Debug, Parser)]
+pub struct ExportCommand {
+    /// Toolchain to export, the default toolchain if omitted
+    pub name: Option<String>,
+    /// Channel to pin the toolchain's components on top of, instead of the revision they come from
+    #[clap(long)]
+    pub channel: Option<String>,
+    /// Where to write the fuel-toolchain.toml [default: ./fuel-toolchain.toml]
+    #[clap(long, short)]
+    pub output: Option<PathBuf>,
+    /// Overwrite an existing fuel-toolchain.toml
+    #[clap(long)]
+    pub force: bool,
+}
+
+#[derive(Debug, Parser)]
+pub struct SearchCommand {
+    /// Component versions the revisions must contain, eg. forc@0.35.1 or fuel-core@^0.17
+    #[clap(required = true)]
+    pub versioned_components: Vec<String>,
+    /// Channel to search the revisions of [possible values: latest, nightly, stable]
+    #[clap(long, default_value = "latest")]
+    pub channel: String,
+    /// Install the most recent matching revision
+    #[clap(long)]
+    pub install: bool,
+    /// Search the revisions fetched last instead of fetching them
+    #[clap(long, conflicts_with = "install")]
+    pub offline: bool,
+}
 
 fn name_allowed(s: &str) -> Result<String> {
     let name = match s.split_once('-') {
@@ -54,7 +130,7 @@ fn name_allowed(s: &str) -> Result<String> {
         None => s,
     };
 
-    if RESERVED_TOOLCHAIN_NAMES.contains(&name) {
+    if reserved_toolchain_names().iter().any(|n| n == name) {
         bail!(
             "Cannot use distributable toolchain name '{}' as a custom toolchain name",
             s
@@ -64,12 +140,18 @@ fn name_allowed(s: &str) -> Result<String> {
     }
 }
 
+pub(crate) fn parse_date(s: &str) -> Result<Date> {
+    Date::parse(s, DATE_FORMAT).map_err(|_| anyhow!("Invalid date '{}', expected YYYY-MM-DD", s))
+}
+
 pub fn exec(command: ToolchainCommand) -> Result<()> {
     match command {
         ToolchainCommand::Install(command) => install(command)?,
         ToolchainCommand::New(command) => new(command)?,
         ToolchainCommand::Uninstall(command) => uninstall(command)?,
         ToolchainCommand::ListRevisions(command) => list_revisions(command)?,
+        ToolchainCommand::Export(command) => export(command)?,
+        ToolchainCommand::Search(command) => search(command)?,
     };
 
     Ok(())
@@ -4,10 +4,14 @@ use clap::Parser;
 use crate::ops::fuelup_update;
 
 #[derive(Debug, Parser)]
-pub struct UpdateCommand {}
+pub struct UpdateCommand {
+    /// Show what would change in each toolchain, without updating it
+    #[clap(long)]
+    pub preview: bool,
+}
 
-pub fn exec() -> Result<()> {
-    fuelup_update::update()?;
+pub fn exec(command: UpdateCommand) -> Result<()> {
+    fuelup_update::update(command)?;
 
     Ok(())
 }
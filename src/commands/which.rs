@@ -0,0 +1,21 @@
+use anyhow::Result;
+use clap::Parser;
+use std::path::PathBuf;
+
+use crate::ops::fuelup_which;
+
+#[derive(Debug, Parser)]
+pub struct WhichCommand {
+    /// Executable to resolve, eg. forc or fuel-core
+    pub executable: String,
+    /// Resolve as if run from within this project directory, instead of the current one
+    #[clap(long)]
+    pub path: Option<PathBuf>,
+    /// Print the result as JSON, eg. for editor integrations
+    #[clap(long)]
+    pub json: bool,
+}
+
+pub fn exec(command: WhichCommand) -> Result<()> {
+    fuelup_which::which(command)
+}
@@ -7,7 +7,7 @@ use std::io::{self, ErrorKind};
 use crate::file::write_file;
 use crate::fmt::format_toolchain_with_target;
 use crate::path::{ensure_dir_exists, hashes_dir, toolchains_dir};
-use crate::toolchain::{DistToolchainDescription, RESERVED_TOOLCHAIN_NAMES};
+use crate::toolchain::{reserved_toolchain_names, DistToolchainDescription};
 
 pub struct Config {
     toolchains_dir: PathBuf,
@@ -56,16 +56,17 @@ impl Config {
         if self.toolchains_dir.is_dir() {
             let mut custom_toolchains: Vec<String> = vec![];
             let mut toolchains: Vec<String> = vec![];
+            let dist_toolchains: Vec<String> = reserved_toolchain_names()
+                .iter()
+                .map(|t| format_toolchain_with_target(t))
+                .collect();
 
             for dir_entry in fs::read_dir(&self.toolchains_dir)?
                 .filter_map(io::Result::ok)
                 .filter(|e| e.file_type().map(|f| f.is_dir()).unwrap_or(false))
             {
                 let toolchain = dir_entry.file_name().to_string_lossy().to_string();
-                if RESERVED_TOOLCHAIN_NAMES
-                    .iter()
-                    .any(|t| toolchain == format_toolchain_with_target(t))
-                {
+                if dist_toolchains.contains(&toolchain) {
                     toolchains.push(toolchain)
                 } else {
                     custom_toolchains.push(toolchain)
@@ -91,10 +92,10 @@ impl Config {
                 .map(|e| e.file_name().into_string().ok().unwrap_or_default())
                 .collect();
 
-            for name in RESERVED_TOOLCHAIN_NAMES {
-                let dist_toolchain = format_toolchain_with_target(name);
+            for name in reserved_toolchain_names() {
+                let dist_toolchain = format_toolchain_with_target(&name);
                 if installed_toolchains.contains(&dist_toolchain) {
-                    dist_toolchains.push(name.to_string())
+                    dist_toolchains.push(name)
                 }
             }
 
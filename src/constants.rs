@@ -2,14 +2,28 @@ use time::{format_description::FormatItem, macros::format_description};
 
 pub const FUELUP_GH_PAGES: &str = "https://raw.githubusercontent.com/FuelLabs/fuelup/gh-pages/";
 pub const FUEL_TOOLCHAIN_TOML_FILE: &str = "fuel-toolchain.toml";
+pub const FUEL_TOOLCHAIN_LOCK_FILE: &str = "fuel-toolchain.lock";
+
+// Environment variables through which the proxy passes its resolved toolchain down to child
+// processes, so that nested proxied calls stay on the same toolchain.
+pub const FUELUP_TOOLCHAIN: &str = "FUELUP_TOOLCHAIN";
+pub const FUELUP_TOOLCHAIN_BIN_DIR: &str = "FUELUP_TOOLCHAIN_BIN_DIR";
+pub const FUELUP_TOOLCHAIN_FILE: &str = "FUELUP_TOOLCHAIN_FILE";
+pub const FUELUP_HOME: &str = "FUELUP_HOME";
+
+// Directory to search for a 'fuel-toolchain.toml' from, instead of the current working directory.
+pub const FUELUP_PROJECT_ROOT: &str = "FUELUP_PROJECT_ROOT";
 
 pub const CHANNEL_LATEST_URL: &str =
     "https://raw.githubusercontent.com/FuelLabs/fuelup/gh-pages/channel-fuel-latest.toml";
 pub const CHANNEL_LATEST_FILE_NAME: &str = "channel-fuel-latest.toml";
 pub const CHANNEL_NIGHTLY_FILE_NAME: &str = "channel-fuel-nightly.toml";
-pub const CHANNEL_BETA_1_FILE_NAME: &str = "channel-fuel-beta-1.toml";
-pub const CHANNEL_BETA_2_FILE_NAME: &str = "channel-fuel-beta-2.toml";
-pub const CHANNEL_BETA_3_FILE_NAME: &str = "channel-fuel-beta-3.toml";
+pub const CHANNEL_STABLE_FILE_NAME: &str = "channel-fuel-stable.toml";
+pub const CHANNEL_INDEX_FILE_NAME: &str = "channels.toml";
+// Published next to the dated copies of a channel, listing its revisions.
+pub const REVISION_INDEX_FILE_NAME: &str = "index.toml";
+// Written within a toolchain installed with '--component' or '--exclude'.
+pub const TOOLCHAIN_SELECTION_FILE_NAME: &str = "selection.toml";
 
 pub const DATE_FORMAT: &[FormatItem] = format_description!("[year]-[month]-[day]");
 pub const DATE_FORMAT_URL_FRIENDLY: &[FormatItem] = format_description!("[year]/[month]/[day]");
@@ -5,6 +5,7 @@ use semver::Version;
 use serde::{Deserialize, Serialize};
 use sha2::Digest;
 use sha2::Sha256;
+use std::collections::BTreeMap;
 use std::env;
 use std::fs::{File, OpenOptions};
 use std::io::{Read, Write};
@@ -17,7 +18,7 @@ use tracing::warn;
 use tracing::{error, info};
 
 use crate::channel::Channel;
-use crate::channel::Package;
+use crate::channel::{HashedBinary, Package};
 use crate::constants::CHANNEL_LATEST_URL;
 use crate::target_triple::TargetTriple;
 use crate::toolchain::DistToolchainDescription;
@@ -33,6 +34,11 @@ struct LatestReleaseApiResponse {
     name: String,
 }
 
+#[derive(Debug, Deserialize)]
+struct ReleaseApiResponse {
+    tag_name: String,
+}
+
 #[derive(Debug, PartialEq, Eq)]
 pub struct DownloadCfg {
     pub name: String,
@@ -88,6 +94,40 @@ impl DownloadCfg {
             hash,
         })
     }
+
+    /// Describes this download as a package for its target, as within a channel. The tarball is
+    /// downloaded to compute its sha256 hash if it is not known yet.
+    pub fn to_package(&self) -> Result<Package> {
+        let hash = match &self.hash {
+            Some(hash) => hash.clone(),
+            None => {
+                let mut hasher = Sha256::new();
+                download(&self.tarball_url, &mut hasher)?;
+                format!("{:x}", hasher.finalize())
+            }
+        };
+
+        Ok(Package {
+            target: BTreeMap::from([(
+                self.target.to_string(),
+                HashedBinary {
+                    url: self.tarball_url.clone(),
+                    hash,
+                },
+            )]),
+            version: self.version.clone(),
+        })
+    }
+
+    /// Whether the tarball of this download has been published, without downloading it.
+    pub fn is_published(&self) -> Result<bool> {
+        let handle = ureq::builder().user_agent("fuelup").build();
+        match handle.head(&self.tarball_url).call() {
+            Ok(_) => Ok(true),
+            Err(ureq::Error::Status(404, _)) => Ok(false),
+            Err(e) => bail!("Unexpected error: {}", e.to_string()),
+        }
+    }
 }
 
 pub fn tarball_name(tarball_prefix: &str, version: &Version, target: &TargetTriple) -> String {
@@ -135,6 +175,48 @@ pub fn get_latest_version(name: &str) -> Result<Version> {
     }
 }
 
+/// Fetches the versions of a component published as GitHub releases, most recent first.
+pub fn get_published_versions(name: &str) -> Result<Vec<Version>> {
+    let component = Component::from_name(name)?;
+    let mut url = Some(format!(
+        "https://api.github.com/repos/FuelLabs/{}/releases?per_page=100",
+        component.repository_name
+    ));
+
+    let handle = ureq::builder().user_agent("fuelup").build();
+    let mut releases: Vec<ReleaseApiResponse> = Vec::new();
+    // Releases are paginated, with the following page given by the 'Link' header.
+    while let Some(page) = url {
+        let resp = handle.get(&page).call()?;
+        url = resp.header("link").and_then(next_page_url);
+        let mut data = Vec::new();
+        resp.into_reader().read_to_end(&mut data)?;
+        releases.extend(serde_json::from_str::<Vec<ReleaseApiResponse>>(
+            &String::from_utf8_lossy(&data),
+        )?);
+    }
+
+    // Tags not following 'v<semver>' are not component releases.
+    Ok(releases
+        .iter()
+        .filter_map(|r| r.tag_name.strip_prefix('v'))
+        .filter_map(|v| Version::parse(v).ok())
+        .collect())
+}
+
+// The URL of the next page within a 'Link' header, eg. '<https://...?page=2>; rel="next", ...'.
+fn next_page_url(link: &str) -> Option<String> {
+    link.split(',').find_map(|link| {
+        let (url, rel) = link.split_once(';')?;
+        (rel.trim() == "rel=\"next\"").then(|| {
+            url.trim()
+                .trim_start_matches('<')
+                .trim_end_matches('>')
+                .to_string()
+        })
+    })
+}
+
 fn unpack(tar_path: &Path, dst: &Path) -> Result<()> {
     let tar_gz = File::open(tar_path)?;
     let decompressed = GzDecoder::new(tar_gz);
@@ -206,7 +288,11 @@ pub fn download_file(url: &str, path: &PathBuf, hasher: &mut Sha256) -> Result<(
         ureq::builder().user_agent("fuelup").build()
     };
 
-    let mut file = OpenOptions::new().write(true).create(true).open(path)?;
+    let mut file = OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .open(path)?;
 
     for _ in 1..RETRY_ATTEMPTS {
         match handle.get(url).call() {
@@ -264,12 +350,14 @@ pub fn download_file_and_unpack(download_cfg: &DownloadCfg, dst_dir_path: &Path)
     };
 
     let actual_hash = format!("{:x}", hasher.finalize());
-    if download_cfg.hash.is_some() && (&actual_hash != download_cfg.hash.as_ref().unwrap()) {
-        bail!(
-            "Attempt to verify sha256 checksum failed:\ndownloaded file: {}\npublished sha256 hash: {}",
-            &actual_hash,
-            download_cfg.hash.as_ref().unwrap()
-        )
+    if let Some(hash) = download_cfg.hash.as_ref() {
+        if &actual_hash != hash {
+            bail!(
+                "Attempt to verify sha256 checksum failed:\ndownloaded file: {}\npublished sha256 hash: {}",
+                &actual_hash,
+                hash
+            )
+        }
     }
 
     unpack(&tarball_path, dst_dir_path)?;
@@ -348,4 +436,16 @@ mod tests {
             Ok(())
         })
     }
+
+    #[test]
+    fn next_page_from_link_header() {
+        let link = r#"<https://api.github.com/repositories/1/releases?per_page=100&page=2>; rel="next", <https://api.github.com/repositories/1/releases?per_page=100&page=4>; rel="last""#;
+        assert_eq!(
+            next_page_url(link).as_deref(),
+            Some("https://api.github.com/repositories/1/releases?per_page=100&page=2")
+        );
+
+        let last = r#"<https://api.github.com/repositories/1/releases?per_page=100&page=3>; rel="prev", <https://api.github.com/repositories/1/releases?per_page=100&page=1>; rel="first""#;
+        assert_eq!(next_page_url(last), None);
+    }
 }
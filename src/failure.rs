@@ -0,0 +1,15 @@
+use std::fmt;
+
+/// An error that makes fuelup exit with a non-zero status once reported, for failures that scripts
+/// and CI have to notice, eg. a toolchain that could only be partially installed. Any other error
+/// is only reported.
+#[derive(Debug)]
+pub struct Failure(pub String);
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for Failure {}
@@ -2,15 +2,25 @@ use anyhow::Result;
 use clap::Parser;
 
 use crate::commands::show::ShowCommand;
-use crate::commands::{check, completions, component, default, fuelup, show, toolchain, update};
+use crate::commands::{
+    bisect, channel, check, completions, component, default, fuelup, lock, overrides, run, set,
+    show, toolchain, update, which,
+};
 
+use crate::commands::bisect::BisectCommand;
+use crate::commands::channel::ChannelCommand;
 use crate::commands::check::CheckCommand;
 use crate::commands::completions::CompletionsCommand;
 use crate::commands::component::ComponentCommand;
 use crate::commands::default::DefaultCommand;
 use crate::commands::fuelup::FuelupCommand;
+use crate::commands::lock::LockCommand;
+use crate::commands::overrides::OverrideCommand;
+use crate::commands::run::RunCommand;
+use crate::commands::set::SetCommand;
 use crate::commands::toolchain::ToolchainCommand;
 use crate::commands::update::UpdateCommand;
+use crate::commands::which::WhichCommand;
 
 #[derive(Debug, Parser)]
 #[clap(name = "fuelup", about = "Fuel Toolchain Manager", version)]
@@ -21,6 +31,11 @@ pub struct Cli {
 
 #[derive(Debug, Parser)]
 enum Commands {
+    /// Find the first revision of a channel for which a command fails
+    Bisect(BisectCommand),
+    /// Inspect published channels
+    #[clap(subcommand)]
+    Channel(ChannelCommand),
     /// Check for updates to Fuel toolchains and fuelup
     Check(CheckCommand),
     /// Generate shell completions
@@ -33,6 +48,16 @@ enum Commands {
     /// Manage your fuelup installation.
     #[clap(name = "self", subcommand)]
     Fuelup(FuelupCommand),
+    /// Write a fuel-toolchain.lock recording the exact binaries the fuel-toolchain.toml resolves to
+    Lock(LockCommand),
+    /// Inspect the fuel-toolchain.toml overriding the toolchain of a project
+    #[clap(subcommand)]
+    Override(OverrideCommand),
+    /// Run a command with the given toolchain, without changing the default toolchain
+    Run(RunCommand),
+    /// Change fuelup settings
+    #[clap(subcommand)]
+    Set(SetCommand),
     /// Install new toolchains or modify/query installed toolchains
     #[clap(subcommand)]
     Toolchain(ToolchainCommand),
@@ -40,12 +65,16 @@ enum Commands {
     Show(ShowCommand),
     /// Updates the distributable toolchains, if already installed
     Update(UpdateCommand),
+    /// Show the path to the executable a proxied command resolves to, and why
+    Which(WhichCommand),
 }
 
 pub fn fuelup_cli() -> Result<()> {
     let cli = Cli::parse();
 
     match cli.command {
+        Commands::Bisect(command) => bisect::exec(command),
+        Commands::Channel(command) => channel::exec(command),
         Commands::Check(command) => check::exec(command),
         Commands::Completions(command) => completions::exec(command),
         Commands::Component(command) => component::exec(command),
@@ -53,8 +82,13 @@ pub fn fuelup_cli() -> Result<()> {
         Commands::Fuelup(command) => match command {
             FuelupCommand::Update => fuelup::exec(),
         },
+        Commands::Lock(command) => lock::exec(command),
+        Commands::Override(command) => overrides::exec(command),
+        Commands::Run(command) => run::exec(command),
+        Commands::Set(command) => set::exec(command),
         Commands::Show(_command) => show::exec(),
         Commands::Toolchain(command) => toolchain::exec(command),
-        Commands::Update(_command) => update::exec(),
+        Commands::Update(command) => update::exec(command),
+        Commands::Which(command) => which::exec(command),
     }
 }
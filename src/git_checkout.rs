@@ -0,0 +1,119 @@
+use anyhow::{bail, Context, Result};
+use component::Component;
+use sha2::{Digest, Sha256};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use tracing::info;
+
+use crate::path::{ensure_dir_exists, git_checkouts_dir};
+
+/// A component built from a git revision, declared as `{ git = "<url>", rev = "<rev>" }` within the
+/// [components] table of a 'fuel-toolchain.toml'.
+///
+/// Each repository and revision is checked out into its own directory under '~/.fuelup/git', and
+/// built there with cargo on first use. A revision that is not a commit, eg. a branch, may move, so
+/// `fuelup toolchain install --from-file` fetches and rebuilds it every time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GitCheckout {
+    pub component: String,
+    pub url: String,
+    pub rev: String,
+}
+
+impl GitCheckout {
+    pub fn new(component: &str, url: &str, rev: &str) -> Self {
+        Self {
+            component: component.to_string(),
+            url: url.to_string(),
+            rev: rev.to_string(),
+        }
+    }
+
+    pub fn dir(&self) -> PathBuf {
+        // The same revision of a component may come from different repositories, eg. forks.
+        let url_hash = format!("{:x}", Sha256::digest(self.url.as_bytes()));
+        let rev = self.rev.replace(['/', '\\'], "-");
+        git_checkouts_dir().join(format!("{}-{}-{rev}", self.component, &url_hash[..16]))
+    }
+
+    /// Whether the revision names a commit rather than eg. a branch or tag.
+    pub fn is_commit(&self) -> bool {
+        (7..=40).contains(&self.rev.len()) && self.rev.chars().all(|c| c.is_ascii_hexdigit())
+    }
+
+    pub fn bin_dir(&self) -> PathBuf {
+        self.dir().join("target").join("release")
+    }
+
+    /// Checks out the revision, reusing an existing checkout, and builds the component's
+    /// executables with `cargo build --release`.
+    pub fn build(&self) -> Result<()> {
+        let dir = self.dir();
+        if !dir.join(".git").is_dir() {
+            ensure_dir_exists(&git_checkouts_dir())?;
+            info!("Cloning {} into {}", self.url, dir.display());
+            run(Command::new("git").arg("clone").arg(&self.url).arg(&dir))?;
+        }
+
+        // A commit may not have been fetched yet if the checkout is an older one, while any other
+        // revision is fetched again in case it has moved.
+        if !self.is_commit() || run(git(&dir).args(["checkout", "--detach", &self.rev])).is_err() {
+            run(git(&dir).args(["fetch", "origin", &self.rev]))?;
+            run(git(&dir).args(["checkout", "--detach", "FETCH_HEAD"]))?;
+        }
+
+        let executables = Component::from_name(&self.component)?.executables;
+        info!(
+            "Building {} at {} from {}",
+            self.component, self.rev, self.url
+        );
+        let mut cargo = Command::new("cargo");
+        cargo.current_dir(&dir).args(["build", "--release"]);
+        for executable in &executables {
+            cargo.args(["--bin", executable]);
+        }
+        run(&mut cargo)
+    }
+}
+
+fn git(dir: &Path) -> Command {
+    let mut cmd = Command::new("git");
+    cmd.arg("-C").arg(dir);
+    cmd
+}
+
+fn run(cmd: &mut Command) -> Result<()> {
+    let status = cmd
+        .status()
+        .with_context(|| format!("Failed to run {:?}", cmd.get_program()))?;
+    if !status.success() {
+        bail!("{:?} failed with {}", cmd, status);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checkout_dir_depends_on_url() {
+        let upstream = GitCheckout::new("forc", "https://github.com/FuelLabs/sway", "abc123");
+        let fork = GitCheckout::new("forc", "https://github.com/someone/sway", "abc123");
+        assert_ne!(upstream.dir(), fork.dir());
+        assert_eq!(
+            upstream.dir(),
+            GitCheckout::new("forc", "https://github.com/FuelLabs/sway", "abc123").dir()
+        );
+    }
+
+    #[test]
+    fn commit_revisions() {
+        let checkout = |rev| GitCheckout::new("forc", "https://github.com/FuelLabs/sway", rev);
+        assert!(checkout("abc1234").is_commit());
+        assert!(checkout("0123456789abcdef0123456789abcdef01234567").is_commit());
+        assert!(!checkout("master").is_commit());
+        assert!(!checkout("feature/abc1234").is_commit());
+        assert!(!checkout("abc12").is_commit());
+    }
+}
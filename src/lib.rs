@@ -1,16 +1,22 @@
 pub mod channel;
+pub mod channel_diff;
+pub mod channel_index;
 pub mod commands;
 pub mod config;
 pub mod constants;
 pub mod download;
+pub mod failure;
 pub mod file;
 pub mod fmt;
 pub mod fuelup_cli;
+pub mod git_checkout;
 pub mod ops;
 pub mod path;
 pub mod proxy_cli;
+pub mod revision_index;
 pub mod settings;
 pub mod store;
 pub mod target_triple;
 pub mod toolchain;
+pub mod toolchain_lock;
 pub mod toolchain_override;
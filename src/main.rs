@@ -1,5 +1,5 @@
 use anyhow::Result;
-use fuelup::{fuelup_cli, proxy_cli};
+use fuelup::{failure::Failure, fuelup_cli, proxy_cli};
 use std::panic;
 use std::path::PathBuf;
 use tracing::error;
@@ -13,18 +13,16 @@ fn run() -> Result<()> {
         .and_then(std::ffi::OsStr::to_str)
         .map(String::from);
 
-    match process_name.as_deref() {
-        Some(component::FUELUP) => {
-            if let Err(e) = fuelup_cli::fuelup_cli() {
-                error!("{}", e);
-            }
-        }
-        Some(n) => {
-            if let Err(e) = proxy_cli::proxy_run(n) {
-                error!("{}", e);
-            }
-        }
+    let result = match process_name.as_deref() {
+        Some(component::FUELUP) => fuelup_cli::fuelup_cli(),
+        Some(n) => proxy_cli::proxy_run(n).map(|_| ()),
         None => panic!("fuelup does not understand this command"),
+    };
+    if let Err(e) = result {
+        error!("{}", e);
+        if e.downcast_ref::<Failure>().is_some() {
+            return Err(e);
+        }
     }
     Ok(())
 }
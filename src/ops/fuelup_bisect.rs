@@ -0,0 +1,195 @@
+use anyhow::{bail, Result};
+use std::ffi::OsString;
+use std::io::ErrorKind;
+use time::Date;
+use tracing::{info, warn};
+
+use crate::{
+    channel_diff::ChannelDiff,
+    commands::bisect::BisectCommand,
+    ops::fuelup_run::toolchain_command,
+    revision_index::{Revision, RevisionIndex},
+    toolchain::{DistToolchainDescription, Toolchain},
+};
+
+pub fn bisect(command: BisectCommand) -> Result<()> {
+    let BisectCommand {
+        channel,
+        good,
+        bad,
+        command,
+        args,
+    } = command;
+
+    if good >= bad {
+        bail!("--good ({}) must be earlier than --bad ({})", good, bad);
+    }
+
+    let index = RevisionIndex::load(&channel, false)?;
+    // Oldest first. The last candidate is the revision that was current on the bad date.
+    let mut candidates: Vec<(Date, &Revision)> = index
+        .between(None, Some(bad))
+        .take_while(|(date, _)| *date > good)
+        .collect();
+    candidates.reverse();
+    if candidates.is_empty() {
+        bail!(
+            "No '{}' revisions were published after {} up to {}",
+            channel,
+            good,
+            bad
+        );
+    }
+
+    info!(
+        "Bisecting {} '{}' revisions published after {} up to {}",
+        candidates.len(),
+        channel,
+        good,
+        bad
+    );
+
+    // Toolchains installed for the bisection are removed afterwards; their binaries remain in
+    // the store, so that bisecting again doesn't download them again.
+    let mut installed = Vec::new();
+    let first_bad = first_bad(candidates.len(), |i| {
+        let (date, _) = candidates[i];
+        is_bad(&channel, date, &command, &args, &mut installed)
+    });
+    for toolchain in installed {
+        if let Err(e) = toolchain.uninstall_self() {
+            warn!(
+                "warning: could not remove toolchain '{}': {}",
+                toolchain.name, e
+            );
+        }
+    }
+    let first_bad = first_bad?;
+
+    let (date, revision) = candidates[first_bad];
+    info!(
+        "\nFirst bad revision:\n{}",
+        revision.summary(&channel, date).trim_end()
+    );
+
+    let last_good = match first_bad {
+        0 => index
+            .on_or_before(good)
+            .and_then(|date| index.revision.get(&date.to_string()).map(|r| (date, r))),
+        i => Some(candidates[i - 1]),
+    };
+    if let Some((good_date, good_revision)) = last_good {
+        let diff = ChannelDiff::new(&good_revision.packages(), &revision.packages());
+        if diff.is_empty() {
+            info!(
+                "\nIt provides the same packages as '{}-{}'",
+                channel, good_date
+            );
+        } else {
+            info!("\nChanges from '{}-{}':\n{}", channel, good_date, diff);
+        }
+    }
+
+    Ok(())
+}
+
+// Binary-searches `len` candidates, oldest first, for the first one `is_bad` holds for, assuming
+// the last candidate is bad.
+fn first_bad(len: usize, mut is_bad: impl FnMut(usize) -> Result<bool>) -> Result<usize> {
+    let (mut lo, mut hi) = (0, len - 1);
+    while lo < hi {
+        let mid = (lo + hi) / 2;
+        let remaining = hi - lo + 1;
+        let steps = usize::BITS - (remaining - 1).leading_zeros();
+        info!("\n{} revisions left, roughly {} steps", remaining, steps);
+
+        if is_bad(mid)? {
+            hi = mid;
+        } else {
+            lo = mid + 1;
+        }
+    }
+    Ok(lo)
+}
+
+// Installs the revision of the given date unless it is already, recording it within `installed`,
+// and runs the command with it. The revision is bad if the command fails.
+fn is_bad(
+    channel: &str,
+    date: Date,
+    command: &OsString,
+    args: &[OsString],
+    installed: &mut Vec<Toolchain>,
+) -> Result<bool> {
+    let description: DistToolchainDescription = format!("{channel}-{date}").parse()?;
+    info!("Testing '{}'", description.channel());
+
+    let toolchain = Toolchain::from_path(&description.to_string());
+    if !toolchain.exists() {
+        toolchain.install_if_nonexistent(&description, None)?;
+        if !toolchain.exists() {
+            bail!("Could not install '{}'", description.channel());
+        }
+        installed.push(Toolchain::from_path(&description.to_string()));
+    }
+
+    let status = match toolchain_command(&toolchain, command)?.args(args).status() {
+        Ok(status) => status,
+        Err(e) if e.kind() == ErrorKind::NotFound => bail!(
+            "command '{}' not found in toolchain '{}' or PATH",
+            command.to_string_lossy(),
+            toolchain.name
+        ),
+        Err(e) => bail!("failed to run '{}': {}", command.to_string_lossy(), e),
+    };
+
+    if status.success() {
+        info!("'{}' is good", description.channel());
+    } else {
+        info!("'{}' is bad", description.channel());
+    }
+    Ok(!status.success())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The index of the first bad candidate, along with the candidates tested to find it.
+    fn bisect(bad: &[bool]) -> (usize, Vec<usize>) {
+        let mut tested = Vec::new();
+        let first_bad = first_bad(bad.len(), |i| {
+            tested.push(i);
+            Ok(bad[i])
+        })
+        .unwrap();
+        (first_bad, tested)
+    }
+
+    #[test]
+    fn single_candidate() {
+        // The only candidate is bad by assumption, so nothing has to be tested.
+        assert_eq!(bisect(&[true]), (0, vec![]));
+    }
+
+    #[test]
+    fn first_candidate_bad() {
+        assert_eq!(bisect(&[true, true, true, true]), (0, vec![1, 0]));
+    }
+
+    #[test]
+    fn last_candidate_only_bad() {
+        assert_eq!(bisect(&[false, false, false, false, true]), (4, vec![2, 3]));
+    }
+
+    #[test]
+    fn error_stops_bisection() {
+        let mut tested = 0;
+        let result = first_bad(4, |_| {
+            tested += 1;
+            bail!("Could not install")
+        });
+        assert!(result.is_err());
+        assert_eq!(tested, 1);
+    }
+}
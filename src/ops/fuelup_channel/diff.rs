@@ -0,0 +1,58 @@
+use anyhow::{bail, Result};
+use std::str::FromStr;
+use tracing::info;
+
+use crate::{
+    channel::Channel,
+    channel_diff::ChannelDiff,
+    channel_index::ChannelIndex,
+    commands::channel::DiffCommand,
+    toolchain::{DistToolchainDescription, Toolchain},
+};
+
+pub fn diff(command: DiffCommand) -> Result<()> {
+    let DiffCommand {
+        from,
+        to,
+        installed,
+    } = command;
+
+    ChannelIndex::refresh_if_unknown(&from);
+    let from = DistToolchainDescription::from_str(&from)?;
+    let to = to.unwrap_or_else(|| from.name.to_string());
+    ChannelIndex::refresh_if_unknown(&to);
+    let to = DistToolchainDescription::from_str(&to)?;
+
+    let toolchain = Toolchain::from_path(&from.to_string());
+    if installed && !toolchain.exists() {
+        bail!("Toolchain '{}' is not installed", toolchain.name);
+    }
+
+    let published = Channel::from_dist_channel(&to)?;
+    let (from_label, from_packages) = if installed {
+        // The published channel of <FROM> may be <TO> itself, eg. when checking for updates.
+        let same_channel = from.channel() == to.channel();
+        let packages = ChannelDiff::installed_packages(&from, same_channel.then_some(&published))?;
+        (format!("installed '{}'", toolchain.name), packages)
+    } else {
+        let (channel, _) = Channel::from_dist_channel(&from)?;
+        (format!("'{}'", from.channel()), channel.pkg)
+    };
+
+    let diff = ChannelDiff::new(&from_packages, &published.0.pkg);
+    if diff.is_empty() {
+        info!(
+            "{} and '{}' provide the same packages",
+            from_label,
+            to.channel()
+        );
+    } else {
+        info!(
+            "Changes from {} to '{}':\n{}",
+            from_label,
+            to.channel(),
+            diff.to_string().trim_end()
+        );
+    }
+    Ok(())
+}
@@ -0,0 +1,68 @@
+use anyhow::Result;
+use serde::Serialize;
+use std::io::Write;
+use std::str::FromStr;
+use tracing::info;
+
+use crate::{
+    channel::Channel, channel_index::ChannelIndex, commands::channel::ShowCommand, fmt::bold,
+    toolchain::DistToolchainDescription,
+};
+
+#[derive(Debug, Serialize)]
+struct ShowOutput {
+    channel: String,
+    hash: String,
+    #[serde(flatten)]
+    content: Channel,
+}
+
+pub fn show(command: ShowCommand) -> Result<()> {
+    let ShowCommand { channel, json } = command;
+
+    ChannelIndex::refresh_if_unknown(&channel);
+    let description = DistToolchainDescription::from_str(&channel)?;
+    // A dated channel that was not published on its date is shown as it was then, without
+    // reporting so in the JSON output.
+    let description = match json {
+        true => description.nearest_revision().unwrap_or(description),
+        false => description.resolve_revision(),
+    };
+    let (content, hash) = Channel::from_dist_channel(&description)?;
+
+    let output = ShowOutput {
+        channel: description.channel(),
+        hash,
+        content,
+    };
+
+    if json {
+        info!("{}", serde_json::to_string_pretty(&output)?);
+        return Ok(());
+    }
+
+    bold(|s| write!(s, "channel: "));
+    info!("{}", output.channel);
+    if let Some(published_by) = &output.content.published_by {
+        bold(|s| write!(s, "published by: "));
+        info!("{}", published_by);
+    }
+    if let Some(date) = &output.content.date {
+        bold(|s| write!(s, "date: "));
+        info!("{}", date);
+    }
+    bold(|s| write!(s, "hash: "));
+    info!("{}", output.hash);
+
+    for (name, package) in &output.content.pkg {
+        bold(|s| write!(s, "\n{name} "));
+        info!("{}", package.version);
+        for (target, binary) in &package.target {
+            info!("  {target}");
+            info!("    url: {}", binary.url);
+            info!("    hash: {}", binary.hash);
+        }
+    }
+
+    Ok(())
+}
@@ -110,7 +110,14 @@ fn check_toolchain(toolchain: &str, verbose: bool) -> Result<()> {
 
     bold(|s| writeln!(s, "{}", &toolchain.name));
 
+    // Components not installed along with the toolchain aren't missing.
+    let selected = toolchain.selected_components()?;
+    let is_skipped = |name: &str| !selected.includes(name) && !toolchain.has_component(name);
+
     for component in Components::collect_exclude_plugins()? {
+        if is_skipped(&component.name) {
+            continue;
+        }
         if let Some(latest_version) = latest_package_versions.get(&component.name) {
             let component_executable = toolchain.bin_path.join(&component.name);
             match Command::new(component_executable).arg("--version").output() {
@@ -133,6 +140,9 @@ fn check_toolchain(toolchain: &str, verbose: bool) -> Result<()> {
 
             if verbose && component.name == component::FORC {
                 for plugin in component::Components::collect_plugins()? {
+                    if plugin.publish.is_some() && is_skipped(&plugin.name) {
+                        continue;
+                    }
                     if !plugin.is_main_executable() {
                         bold(|s| writeln!(s, "    - {}", plugin.name));
                     }
@@ -23,6 +23,10 @@ fn format_installable_component_info(name: &str, latest_version: &str) -> String
     format!("  {name} (latest: {latest_version})\n")
 }
 
+fn format_unmanaged_plugin_info(name: &str, path: &str) -> String {
+    format!("  {name} ({path})\n")
+}
+
 fn format_forc_default_plugins(plugin_executables: Vec<String>) -> String {
     format!(
         "    - {}\n",
@@ -92,6 +96,18 @@ pub fn list(_command: ListCommand) -> Result<()> {
             }
         }
     }
+
+    let unmanaged_plugins = toolchain.unmanaged_plugins()?;
+    if !unmanaged_plugins.is_empty() {
+        available_components_summary.push_str("\nUnmanaged plugins:\n");
+        for (name, path) in unmanaged_plugins {
+            available_components_summary.push_str(&format_unmanaged_plugin_info(
+                &name,
+                &path.display().to_string(),
+            ));
+        }
+    }
+
     info!(
         "{}\n{}",
         installed_components_summary, available_components_summary
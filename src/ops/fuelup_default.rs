@@ -17,12 +17,7 @@ pub fn default(toolchain: Option<String>) -> Result<()> {
             let current_toolchain = Toolchain::from_settings()?;
 
             if let Some(to) = ToolchainOverride::from_project_root() {
-                let name =
-                    match DistToolchainDescription::from_str(&to.cfg.toolchain.channel.to_string())
-                    {
-                        Ok(desc) => desc.to_string(),
-                        Err(_) => to.cfg.toolchain.channel.to_string(),
-                    };
+                let name = to.cfg.toolchain.toolchain_name();
                 result.push_str(&format!("{name} (override)"));
 
                 if current_toolchain.exists() {
@@ -0,0 +1,47 @@
+use anyhow::{bail, Result};
+use tracing::info;
+
+use crate::{
+    commands::lock::LockCommand, constants::FUEL_TOOLCHAIN_TOML_FILE,
+    path::get_fuel_toolchain_toml, toolchain_lock::ToolchainLock,
+    toolchain_override::ToolchainOverride,
+};
+
+pub fn lock(command: LockCommand) -> Result<()> {
+    let LockCommand { update } = command;
+
+    let Some(toml_path) = get_fuel_toolchain_toml() else {
+        bail!(
+            "No '{}' found in the current directory or its parents",
+            FUEL_TOOLCHAIN_TOML_FILE
+        );
+    };
+    // Unlike proxies, which only warn about an invalid file, locking one fails with the reason.
+    let to = ToolchainOverride::from_path(toml_path)?;
+
+    let path = ToolchainLock::path(&to);
+    let previous = if update {
+        None
+    } else {
+        ToolchainLock::from_path(&path)?
+    };
+
+    let lock = ToolchainLock::resolve(&to, previous.as_ref())?;
+    if previous.as_ref() == Some(&lock) {
+        info!("{} is up to date", path.display());
+        return Ok(());
+    }
+    lock.write(&path)?;
+
+    info!("Locked '{}' in {}", lock.toolchain.channel, path.display());
+    for (name, package) in &lock.pkg {
+        if !lock.components.contains_key(name) {
+            info!("  {name} {}", package.version);
+        }
+    }
+    for (name, package) in &lock.components {
+        info!("  {name} {} (pinned)", package.version);
+    }
+
+    Ok(())
+}
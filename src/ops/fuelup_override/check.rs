@@ -0,0 +1,131 @@
+use anyhow::{bail, Result};
+use component::Components;
+use tracing::{error, info};
+
+use crate::{
+    channel::Channel,
+    commands::overrides::CheckCommand,
+    constants::FUEL_TOOLCHAIN_TOML_FILE,
+    download::DownloadCfg,
+    failure::Failure,
+    path::{find_fuel_toolchain_toml, get_fuel_toolchain_toml},
+    target_triple::TargetTriple,
+    toolchain::Toolchain,
+    toolchain_override::{ComponentSpec, ToolchainOverride},
+};
+
+pub fn check(command: CheckCommand) -> Result<()> {
+    let CheckCommand { path } = command;
+
+    let toml_path = match &path {
+        Some(dir) => find_fuel_toolchain_toml(dir),
+        None => get_fuel_toolchain_toml(),
+    };
+    let Some(toml_path) = toml_path else {
+        bail!(
+            "No '{}' found in the project directory or its parents",
+            FUEL_TOOLCHAIN_TOML_FILE
+        );
+    };
+
+    let problems = match ToolchainOverride::from_path(toml_path.clone()) {
+        Ok(to) => check_override(&to),
+        Err(e) => vec![format!("invalid {}: {}", FUEL_TOOLCHAIN_TOML_FILE, e)],
+    };
+
+    if problems.is_empty() {
+        info!("{} is valid", toml_path.display());
+        return Ok(());
+    }
+
+    for problem in &problems {
+        error!("error: {problem}");
+    }
+    bail!(Failure(format!(
+        "{} problem{} found in {}",
+        problems.len(),
+        if problems.len() == 1 { "" } else { "s" },
+        toml_path.display()
+    )));
+}
+
+// Collects every problem within a parsed 'fuel-toolchain.toml', rather than stopping at the first.
+fn check_override(to: &ToolchainOverride) -> Vec<String> {
+    let mut problems = Vec::new();
+
+    match to.cfg.toolchain.description() {
+        Some(description) => {
+            if let Err(e) = Channel::from_dist_channel(&description) {
+                problems.push(format!(
+                    "channel '{}' is not published: {}",
+                    to.cfg.toolchain, e
+                ));
+            }
+        }
+        None => {
+            let toolchain = Toolchain::from_path(&to.cfg.toolchain.toolchain_name());
+            if !toolchain.exists() {
+                problems.push(format!(
+                    "custom toolchain '{}' is not installed; you may create it using 'fuelup toolchain new {}'",
+                    toolchain.name, toolchain.name
+                ));
+            }
+        }
+    }
+
+    let mut components: Vec<_> = to.cfg.components.iter().flatten().collect();
+    components.sort_by_key(|(name, _)| name.as_str());
+    for (name, spec) in components {
+        if let Err(problem) = check_component(to, name, spec) {
+            problems.push(problem);
+        }
+    }
+
+    problems
+}
+
+fn check_component(to: &ToolchainOverride, name: &str, spec: &ComponentSpec) -> Result<(), String> {
+    if !Components::collect()
+        .map_err(|e| e.to_string())?
+        .component
+        .contains_key(name)
+    {
+        return Err(format!("'{name}' in [components] is not a known component"));
+    }
+
+    match spec {
+        ComponentSpec::Path(dir) => {
+            let dir = to
+                .path
+                .parent()
+                .map_or(dir.clone(), |parent| parent.join(dir));
+            if !dir.is_dir() {
+                return Err(format!(
+                    "the path declared for '{name}' does not exist: {}",
+                    dir.display()
+                ));
+            }
+        }
+        // Checking a git revision would require fetching it; it is verified when it is built.
+        ComponentSpec::Git { .. } => {}
+        ComponentSpec::Version(_) | ComponentSpec::VersionReq(_) => {
+            let version = spec
+                .resolve_published(name)
+                .map_err(|e| format!("could not resolve '{name}' {spec}: {e}"))?;
+            let target = TargetTriple::from_component(name).map_err(|e| e.to_string())?;
+            let download_cfg = DownloadCfg::new(name, target.clone(), Some(version.clone()))
+                .map_err(|e| e.to_string())?;
+            match download_cfg.is_published() {
+                Ok(true) => {}
+                Ok(false) => return Err(format!("{name} {version} is not published for {target}")),
+                Err(e) => {
+                    return Err(format!(
+                        "could not check whether {name} {version} is published: {e}"
+                    ))
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,4 @@
+pub mod check;
+pub mod pin;
+pub mod set_channel;
+pub mod unpin;
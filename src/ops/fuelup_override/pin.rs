@@ -0,0 +1,28 @@
+use anyhow::Result;
+use tracing::info;
+
+use crate::{
+    commands::overrides::PinCommand,
+    toolchain_override::{ComponentSpec, OverrideDocument},
+};
+
+pub fn pin(command: PinCommand) -> Result<()> {
+    let PinCommand {
+        versioned_component,
+        path,
+    } = command;
+
+    let (component, spec) = ComponentSpec::from_versioned_component(&versioned_component)?;
+
+    let mut document = OverrideDocument::find(path.as_deref())?;
+    document.pin(&component, &spec)?;
+    document.save()?;
+
+    info!(
+        "Pinned {} to '{}' in {}",
+        component,
+        spec,
+        document.path.display()
+    );
+    Ok(())
+}
@@ -0,0 +1,23 @@
+use anyhow::Result;
+use tracing::info;
+
+use crate::{
+    channel_index::ChannelIndex, commands::overrides::SetChannelCommand,
+    toolchain_override::OverrideDocument,
+};
+
+pub fn set_channel(command: SetChannelCommand) -> Result<()> {
+    let SetChannelCommand { channel, path } = command;
+
+    ChannelIndex::refresh_if_unknown(&channel);
+    let mut document = OverrideDocument::find(path.as_deref())?;
+    document.set_channel(&channel)?;
+    document.save()?;
+
+    info!(
+        "Set channel to '{}' in {}",
+        channel,
+        document.path.display()
+    );
+    Ok(())
+}
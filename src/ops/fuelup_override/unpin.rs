@@ -0,0 +1,21 @@
+use anyhow::{bail, Result};
+use tracing::info;
+
+use crate::{commands::overrides::UnpinCommand, toolchain_override::OverrideDocument};
+
+pub fn unpin(command: UnpinCommand) -> Result<()> {
+    let UnpinCommand { component, path } = command;
+
+    let mut document = OverrideDocument::find(path.as_deref())?;
+    if !document.unpin(&component) {
+        bail!(
+            "'{}' is not pinned in {}",
+            component,
+            document.path.display()
+        );
+    }
+    document.save()?;
+
+    info!("Unpinned {} in {}", component, document.path.display());
+    Ok(())
+}
@@ -0,0 +1,57 @@
+use anyhow::{bail, Result};
+use std::env;
+use std::ffi::OsStr;
+use std::io::ErrorKind;
+use std::os::unix::prelude::CommandExt;
+use std::process::Command;
+
+use crate::{
+    commands::run::RunCommand,
+    constants::{FUELUP_HOME, FUELUP_TOOLCHAIN, FUELUP_TOOLCHAIN_BIN_DIR, FUELUP_TOOLCHAIN_FILE},
+    path::fuelup_dir,
+    toolchain::Toolchain,
+};
+
+// A command run with the given toolchain. The toolchain's bin dir is prepended to PATH so that
+// the command and anything it spawns picks up this toolchain's executables first.
+pub(crate) fn toolchain_command(toolchain: &Toolchain, command: &OsStr) -> Result<Command> {
+    let mut paths = vec![toolchain.bin_path.clone()];
+    if let Some(path) = env::var_os("PATH") {
+        paths.extend(env::split_paths(&path));
+    }
+
+    let mut cmd = Command::new(command);
+    cmd.env("PATH", env::join_paths(paths)?)
+        .env(FUELUP_TOOLCHAIN, &toolchain.name)
+        .env(FUELUP_TOOLCHAIN_BIN_DIR, &toolchain.bin_path)
+        .env(FUELUP_HOME, fuelup_dir())
+        .env_remove(FUELUP_TOOLCHAIN_FILE);
+    Ok(cmd)
+}
+
+pub fn run(command: RunCommand) -> Result<()> {
+    let RunCommand {
+        toolchain,
+        command,
+        args,
+    } = command;
+
+    let (toolchain, description) = Toolchain::from_selector(&toolchain)?;
+    if let Some(description) = description {
+        toolchain.install_if_nonexistent(&description, None)?;
+    }
+
+    let mut cmd = toolchain_command(&toolchain, &command)?;
+    cmd.args(args);
+
+    // exec() only returns on failure; on success the exit code of the command is our own.
+    let error = cmd.exec();
+    match error.kind() {
+        ErrorKind::NotFound => bail!(
+            "command '{}' not found in toolchain '{}' or PATH",
+            command.to_string_lossy(),
+            toolchain.name
+        ),
+        _ => bail!("failed to run '{}': {}", command.to_string_lossy(), error),
+    }
+}
@@ -0,0 +1,50 @@
+use anyhow::Result;
+use component::{Components, DEFAULT_PROFILE};
+use tracing::info;
+
+use crate::{
+    path::settings_file,
+    settings::{AutoInstall, SettingsFile},
+};
+
+pub fn auto_install(policy: Option<AutoInstall>) -> Result<()> {
+    let settings = SettingsFile::new(settings_file());
+
+    match policy {
+        Some(policy) => {
+            settings.with_mut(|s| {
+                s.auto_install = Some(policy);
+                Ok(())
+            })?;
+            info!("auto-install policy set to '{}'", policy);
+        }
+        None => {
+            let policy = settings.with(|s| Ok(s.auto_install.unwrap_or_default()))?;
+            info!("{}", policy);
+        }
+    }
+
+    Ok(())
+}
+
+pub fn profile(profile: Option<String>) -> Result<()> {
+    let settings = SettingsFile::new(settings_file());
+
+    match profile {
+        Some(profile) => {
+            // Fails on unknown profiles.
+            Components::profile(&profile)?;
+            settings.with_mut(|s| {
+                s.profile = Some(profile.clone());
+                Ok(())
+            })?;
+            info!("profile set to '{}'", profile);
+        }
+        None => {
+            let profile = settings.with(|s| Ok(s.profile.clone()))?;
+            info!("{}", profile.as_deref().unwrap_or(DEFAULT_PROFILE));
+        }
+    }
+
+    Ok(())
+}
@@ -1,7 +1,6 @@
 use anyhow::Result;
 use component::{self, Components};
 use semver::Version;
-use std::str::FromStr;
 use std::{io::Write, path::Path};
 use tracing::{error, info};
 
@@ -10,7 +9,7 @@ use crate::{
     fmt::{bold, print_header},
     path::fuelup_dir,
     target_triple::TargetTriple,
-    toolchain::{DistToolchainDescription, Toolchain},
+    toolchain::Toolchain,
     toolchain_override::ToolchainOverride,
 };
 
@@ -57,16 +56,9 @@ pub fn show() -> Result<()> {
 
     let toolchain_override = ToolchainOverride::from_project_root();
 
-    let override_name = if let Some(toolchain_override) = toolchain_override.as_ref() {
-        match DistToolchainDescription::from_str(
-            &toolchain_override.cfg.toolchain.channel.to_string(),
-        ) {
-            Ok(desc) => Some(desc.to_string()),
-            Err(_) => Some(toolchain_override.cfg.toolchain.channel.to_string()),
-        }
-    } else {
-        None
-    };
+    let override_name = toolchain_override
+        .as_ref()
+        .map(|to| to.cfg.toolchain.toolchain_name());
 
     for toolchain in cfg.list_toolchains()? {
         let mut message = toolchain.clone();
@@ -125,5 +117,14 @@ pub fn show() -> Result<()> {
         }
     }
 
+    let unmanaged_plugins = active_toolchain.unmanaged_plugins()?;
+    if !unmanaged_plugins.is_empty() {
+        bold(|s| writeln!(s, "  unmanaged plugins"));
+        for (name, path) in unmanaged_plugins {
+            bold(|s| write!(s, "    - {name}"));
+            info!(" : {}", path.display());
+        }
+    }
+
     Ok(())
 }
@@ -0,0 +1,155 @@
+use anyhow::{bail, Result};
+use std::{env, fs, str::FromStr};
+use tracing::{info, warn};
+
+use crate::{
+    channel_index::DATED_CHANNELS,
+    commands::toolchain::ExportCommand,
+    config::Config,
+    constants::FUEL_TOOLCHAIN_TOML_FILE,
+    revision_index::{Revision, RevisionIndex},
+    target_triple::TargetTriple,
+    toolchain::{DistToolchainDescription, DistToolchainName, Toolchain},
+    toolchain_override::{ComponentSpec, OverrideDocument},
+};
+
+pub fn export(command: ExportCommand) -> Result<()> {
+    let ExportCommand {
+        name,
+        channel,
+        output,
+        force,
+    } = command;
+
+    let toolchain = match &name {
+        Some(name) => Toolchain::from_selector(name)?.0,
+        None => Toolchain::from_settings()?,
+    };
+    if !toolchain.exists() {
+        bail!("Toolchain '{}' is not installed", toolchain.name);
+    }
+
+    let path = match output {
+        Some(path) => path,
+        None => env::current_dir()?.join(FUEL_TOOLCHAIN_TOML_FILE),
+    };
+    if path.exists() && !force {
+        bail!(
+            "{} already exists; use --force to overwrite it",
+            path.display()
+        );
+    }
+
+    // Distributable toolchains are named after their channel, followed by the host target.
+    let target_suffix = format!("-{}", TargetTriple::from_host()?);
+    let dist_channel = toolchain
+        .name
+        .strip_suffix(&target_suffix)
+        .filter(|c| DistToolchainDescription::from_str(c).is_ok());
+
+    let mut document = OverrideDocument::new(path);
+    let pin_components = match (channel, dist_channel) {
+        (Some(channel), _) => {
+            document.set_channel(&channel)?;
+            true
+        }
+        // A dated or named channel already reproduces the toolchain as a whole.
+        (None, Some(channel)) if is_reproducible(channel) => {
+            document.set_channel(channel)?;
+            false
+        }
+        // A channel that keeps being republished is reproduced by the revision the toolchain was
+        // installed from, as recorded by its hash.
+        (None, Some(channel)) => {
+            let hash = fs::read_to_string(Config::from_env()?.hashes_dir().join(&toolchain.name));
+            let revision = match hash {
+                Ok(hash) => find_revision(channel, |revision| revision.hash == hash)?,
+                Err(_) => None,
+            };
+            let Some(revision) = revision else {
+                bail!(
+                    "Could not find the revision of '{}' that '{}' was installed from; use --channel <channel> to pin its components on top of a dated channel",
+                    channel,
+                    toolchain.name
+                );
+            };
+            document.set_channel(&revision)?;
+            false
+        }
+        // A custom toolchain is pinned on top of the most recent revision containing its components.
+        (None, None) => {
+            let versions = toolchain.component_versions()?;
+            let mut revision = None;
+            if !versions.is_empty() && versions.values().all(Option::is_some) {
+                for channel in DATED_CHANNELS {
+                    let contains_versions = |revision: &Revision| {
+                        versions
+                            .iter()
+                            .all(|(name, version)| revision.pkg.get(name) == version.as_ref())
+                    };
+                    // Channels whose revisions can't be loaded are simply not considered.
+                    revision = find_revision(channel, contains_versions).unwrap_or_default();
+                    if revision.is_some() {
+                        break;
+                    }
+                }
+            }
+            let Some(revision) = revision else {
+                bail!(
+                    "Could not find a revision containing the components of custom toolchain '{}'; use --channel <channel> to pin its components on top of a channel",
+                    toolchain.name
+                );
+            };
+            document.set_channel(&revision)?;
+            true
+        }
+    };
+
+    let mut pinned = vec![];
+    if pin_components {
+        for (component, version) in toolchain.component_versions()? {
+            match version {
+                Some(version) => {
+                    document.pin(&component, &ComponentSpec::Version(version.clone()))?;
+                    pinned.push((component, version));
+                }
+                None => warn!(
+                    "warning: could not determine the version of {} in '{}'; it is not pinned",
+                    component, toolchain.name
+                ),
+            }
+        }
+    }
+    document.save()?;
+
+    info!(
+        "Exported '{}' to {}",
+        toolchain.name,
+        document.path.display()
+    );
+    for (name, version) in pinned {
+        info!("  {name} {version}");
+    }
+
+    Ok(())
+}
+
+// The most recent revision of a channel matching `is_match`, eg. 'latest-2023-01-27'. Revisions
+// don't change once published, so the cached index is only refreshed when nothing in it matches.
+fn find_revision(channel: &str, is_match: impl Fn(&Revision) -> bool) -> Result<Option<String>> {
+    let find = |index: RevisionIndex| {
+        index
+            .between(None, None)
+            .find(|(_, revision)| is_match(revision))
+            .map(|(date, _)| format!("{channel}-{date}"))
+    };
+    if let Some(revision) = RevisionIndex::load(channel, true).ok().and_then(find) {
+        return Ok(Some(revision));
+    }
+    Ok(find(RevisionIndex::load(channel, false)?))
+}
+
+fn is_reproducible(channel: &str) -> bool {
+    DistToolchainDescription::from_str(channel)
+        .is_ok_and(|d| d.date.is_some() || matches!(d.name, DistToolchainName::Named(_)))
+}
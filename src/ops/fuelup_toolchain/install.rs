@@ -1,18 +1,134 @@
+use crate::channel_index::{ChannelIndex, DATED_CHANNELS};
 use crate::config::Config;
-use crate::path::{settings_file, warn_existing_fuel_executables};
-use crate::settings::SettingsFile;
+use crate::constants::FUEL_TOOLCHAIN_TOML_FILE;
+use crate::failure::Failure;
+use crate::file::hard_or_symlink_file;
+use crate::git_checkout::GitCheckout;
+use crate::path::{
+    fuelup_bin, fuelup_bin_dir, get_fuel_toolchain_toml, settings_file,
+    warn_existing_fuel_executables,
+};
+use crate::settings::{default_profile, SettingsFile};
+use crate::store::Store;
 use crate::toolchain::{DistToolchainDescription, Toolchain};
+use crate::toolchain_lock::ToolchainLock;
+use crate::toolchain_override::{ComponentSpec, ToolchainOverride};
 use crate::{channel::Channel, commands::toolchain::InstallCommand};
 use anyhow::{bail, Result};
+use component::{Components, Profile};
 use std::fmt::Write;
 use std::str::FromStr;
-use tracing::{error, info};
+use tracing::info;
 
 pub fn install(command: InstallCommand) -> Result<()> {
-    let InstallCommand { name } = command;
+    let InstallCommand {
+        name,
+        from_file,
+        as_of,
+        profile,
+        components,
+        exclude,
+    } = command;
+    let profile_given = profile.is_some();
+    let profile = match profile {
+        Some(profile) => Components::profile(&profile)?,
+        None => default_profile()?,
+    };
+    // A profile given explicitly is remembered like any other selection of components.
+    let selection = select_components(&profile, components, exclude)?
+        .or_else(|| profile_given.then(|| profile.clone()));
+    let profile = selection.as_ref().unwrap_or(&profile);
+
+    match name {
+        Some(name) if !from_file => {
+            ChannelIndex::refresh_if_unknown(&name);
+            let mut description = DistToolchainDescription::from_str(&name)?;
+            if let Some(date) = as_of {
+                if description.date.is_some()
+                    || !DATED_CHANNELS.contains(&description.name.to_string().as_str())
+                {
+                    bail!(
+                        "--as-of only applies to channels published by date; expected one of {}",
+                        DATED_CHANNELS.join(", ")
+                    );
+                }
+                description.date = Some(date);
+            }
+            let description = description.resolve_revision();
+            install_toolchain(&description, &description, None, profile)?;
+            apply_selection(&description, selection.as_ref())
+        }
+        _ => install_from_file(profile, selection.as_ref()),
+    }
+}
+
+// The components selected through '--component', or the ones of `profile` but those given through
+// '--exclude'. Returns `None` if neither is given.
+fn select_components(
+    profile: &Profile,
+    components: Vec<String>,
+    exclude: Vec<String>,
+) -> Result<Option<Profile>> {
+    let published: Vec<String> = Components::collect_publishables()?
+        .into_iter()
+        .map(|c| c.name)
+        .collect();
+    if let Some(unknown) = components
+        .iter()
+        .chain(&exclude)
+        .find(|c| !published.contains(c))
+    {
+        bail!(
+            "'{}' is not a published component; expected one of {}",
+            unknown,
+            published.join(", ")
+        );
+    }
 
-    let description = DistToolchainDescription::from_str(&name)?;
+    if !components.is_empty() {
+        return Ok(Some(Profile { components }));
+    }
+    if !exclude.is_empty() {
+        let components = profile
+            .components
+            .iter()
+            .filter(|c| !exclude.contains(c))
+            .cloned()
+            .collect();
+        return Ok(Some(Profile { components }));
+    }
+    Ok(None)
+}
 
+// Remembers the components selected for an installed toolchain, so that updates don't add the
+// others back, and removes the ones installed previously but no longer selected.
+fn apply_selection(
+    description: &DistToolchainDescription,
+    selection: Option<&Profile>,
+) -> Result<()> {
+    let toolchain = Toolchain::from_path(&description.to_string());
+    if !toolchain.exists() {
+        return Ok(());
+    }
+    if let Some(selection) = selection {
+        for component in Components::collect_publishables()? {
+            if !selection.includes(&component.name) && toolchain.has_component(&component.name) {
+                toolchain.remove_component(&component.name)?;
+            }
+        }
+    }
+    toolchain.save_selection(selection)
+}
+
+/// Installs the components of the channel `revision` included in `profile`, as the toolchain of
+/// `description`. Given a `lock`, the install fails unless the channel still provides the locked
+/// binaries.
+pub(crate) fn install_toolchain(
+    description: &DistToolchainDescription,
+    revision: &DistToolchainDescription,
+    lock: Option<&ToolchainLock>,
+    profile: &Profile,
+) -> Result<()> {
     let settings_file = settings_file();
     if !settings_file.exists() {
         let settings = SettingsFile::new(settings_file);
@@ -29,12 +145,28 @@ pub fn install(command: InstallCommand) -> Result<()> {
     warn_existing_fuel_executables()?;
 
     let toolchain = Toolchain::from_path(&description.to_string());
-    let (cfgs, hash) = if let Ok((channel, hash)) = Channel::from_dist_channel(&description) {
-        if let Ok(true) = config.hash_matches(&description, &hash) {
-            info!("'{}' is already installed and up to date", toolchain.name);
-            return Ok(());
-        };
-        (channel.build_download_configs(), hash)
+    let (cfgs, hash) = if let Ok((channel, hash)) = Channel::from_dist_channel(revision) {
+        if let Some(lock) = lock {
+            lock.verify_channel(&channel)?;
+        }
+        let cfgs = channel.build_download_configs(profile);
+        // The hash comes from the revision installed, but is saved under the name the toolchain
+        // is installed as, so that a dated toolchain is checked against the revision it resolved to.
+        match config.hash_matches(description, &hash) {
+            // Up to date, though the profile may include components not installed yet.
+            Ok(true) => {
+                let missing: Vec<_> = cfgs
+                    .into_iter()
+                    .filter(|cfg| !toolchain.has_component(&cfg.name))
+                    .collect();
+                if missing.is_empty() {
+                    info!("'{}' is already installed and up to date", toolchain.name);
+                    return Ok(());
+                }
+                (missing, hash)
+            }
+            _ => (cfgs, hash),
+        }
     } else {
         bail!("Could not build download configs from channel")
     };
@@ -53,18 +185,123 @@ pub fn install(command: InstallCommand) -> Result<()> {
         };
     }
 
-    if errored_bins.is_empty() {
-        config.save_hash(&toolchain.name, &hash)?;
-        info!("\nInstalled:\n{}", installed_bins);
-        info!("\nThe Fuel toolchain is installed and up to date");
-    } else if installed_bins.is_empty() {
-        error!("\nfuelup failed to install:\n{}", errored_bins)
-    } else {
-        info!(
-            "\nThe Fuel toolchain is partially installed.\nfuelup failed to install: {}",
-            errored_bins
+    if !errored_bins.is_empty() {
+        if installed_bins.is_empty() {
+            bail!(Failure(format!(
+                "\nfuelup failed to install:\n{errored_bins}"
+            )));
+        }
+        bail!(Failure(format!(
+            "\nThe Fuel toolchain is partially installed.\nfuelup failed to install: {errored_bins}"
+        )));
+    }
+
+    config.save_hash(&description.to_string(), &hash)?;
+    info!("\nInstalled:\n{}", installed_bins);
+    info!("\nThe Fuel toolchain is installed and up to date");
+
+    Ok(())
+}
+
+/// Installs the toolchain declared by the nearest 'fuel-toolchain.toml', along with every
+/// component pinned within its [components] table, instead of waiting for proxies to install
+/// them on first use.
+fn install_from_file(profile: &Profile, selection: Option<&Profile>) -> Result<()> {
+    let Some(path) = get_fuel_toolchain_toml() else {
+        bail!(
+            "No '{}' found in the current directory or its parents",
+            FUEL_TOOLCHAIN_TOML_FILE
         );
     };
+    let to = ToolchainOverride::from_path(path)?;
+    let lock = ToolchainLock::for_override(&to)?;
+
+    let toolchain = Toolchain::from_path(&to.cfg.toolchain.toolchain_name());
+    match to.cfg.toolchain.description() {
+        Some(description) => {
+            // The toolchain keeps the name declared in the file, even if its channel was not
+            // published on its date.
+            let revision = description.clone().resolve_revision();
+            install_toolchain(&description, &revision, lock.as_ref(), profile)?;
+            apply_selection(&description, selection)?;
+        }
+        None => {
+            if !toolchain.exists() {
+                bail!(
+                    "toolchain '{}' declared in {} is not installed; you may create it using 'fuelup toolchain new {}'",
+                    toolchain.name,
+                    to.path.display(),
+                    toolchain.name
+                );
+            }
+        }
+    }
+
+    let mut summary = String::new();
+    let mut components: Vec<_> = to.cfg.components.iter().flatten().collect();
+    components.sort_by_key(|(name, _)| name.as_str());
+    for (name, spec) in components {
+        match spec {
+            ComponentSpec::Path(dir) => {
+                // Binaries within a path are built by the developer, so there is nothing to install.
+                let dir = to
+                    .path
+                    .parent()
+                    .map_or(dir.clone(), |parent| parent.join(dir));
+                writeln!(summary, "- {name} from {}", dir.display())?;
+            }
+            ComponentSpec::Git { url, rev } => {
+                let checkout = GitCheckout::new(name, url, rev);
+                if !checkout.is_commit() || !checkout.bin_dir().join(name).exists() {
+                    checkout.build()?;
+                }
+                writeln!(summary, "- {name} built from {spec}")?;
+            }
+            ComponentSpec::Version(_) | ComponentSpec::VersionReq(_) => {
+                let version = match lock.as_ref().and_then(|l| l.locked_version(name)) {
+                    Some(version) => version.clone(),
+                    None => spec.resolve_latest(name)?,
+                };
+                let store = Store::from_env()?;
+                if !store.has_component(name, &version) {
+                    let download_cfg =
+                        ToolchainLock::pinned_download_cfg(lock.as_ref(), name, &version)?;
+                    store.install_component(&download_cfg)?;
+                }
+                writeln!(summary, "- {name} {version} (pinned)")?;
+            }
+        }
+        link_proxies(name)?;
+    }
+
+    info!(
+        "\nInstalled '{}' as declared in {}",
+        toolchain.name,
+        to.path.display()
+    );
+    if !summary.is_empty() {
+        info!("{}", summary.trim_end());
+    }
 
     Ok(())
 }
+
+// Pinned components may provide executables that no installed toolchain does, eg. forc-wallet,
+// which have to be proxied by fuelup to take effect.
+fn link_proxies(component: &str) -> Result<()> {
+    let fuelup_bin = fuelup_bin();
+    if !fuelup_bin.is_file() {
+        return Ok(());
+    }
+    let Some(component) = Components::collect()?.component.remove(component) else {
+        return Ok(());
+    };
+    let fuelup_bin_dir = fuelup_bin_dir();
+    for executable in &component.executables {
+        let proxy = fuelup_bin_dir.join(executable);
+        if !proxy.exists() {
+            hard_or_symlink_file(&fuelup_bin, &proxy)?;
+        }
+    }
+    Ok(())
+}
@@ -1,4 +1,6 @@
+pub mod export;
 pub mod install;
 pub mod list_revisions;
 pub mod new;
+pub mod search;
 pub mod uninstall;
@@ -0,0 +1,83 @@
+use anyhow::{bail, Result};
+use std::str::FromStr;
+use tracing::info;
+
+use crate::{
+    commands::toolchain::SearchCommand,
+    ops::fuelup_toolchain::install::install_toolchain,
+    revision_index::RevisionIndex,
+    toolchain::{DistToolchainDescription, Toolchain},
+    toolchain_override::ComponentSpec,
+};
+
+pub fn search(command: SearchCommand) -> Result<()> {
+    let SearchCommand {
+        versioned_components,
+        channel,
+        install,
+        offline,
+    } = command;
+
+    let specs = versioned_components
+        .iter()
+        .map(|c| ComponentSpec::from_versioned_component(c))
+        .collect::<Result<Vec<_>>>()?;
+    if let Some((component, spec)) = specs.iter().find(|(_, spec)| !spec.is_versioned()) {
+        bail!(
+            "Only released versions can be searched for, but got '{}@{}'",
+            component,
+            spec
+        );
+    }
+    let wanted = specs
+        .iter()
+        .map(|(component, spec)| format!("{component} {spec}"))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let index = RevisionIndex::load(&channel, offline)?;
+    let matches: Vec<_> = index
+        .between(None, None)
+        .filter(|(_, revision)| {
+            specs.iter().all(|(component, spec)| {
+                revision
+                    .pkg
+                    .get(component)
+                    .is_some_and(|version| spec.matches(version))
+            })
+        })
+        .collect();
+
+    let Some((newest, _)) = matches.first() else {
+        // Revisions published before the index was introduced are not recorded in it.
+        match index.between(None, None).last() {
+            Some((earliest, _)) => info!(
+                "No '{}' revision since {} contains {}; earlier revisions are not covered by the revision index",
+                channel, earliest, wanted
+            ),
+            None => info!("No '{}' revision contains {}", channel, wanted),
+        }
+        return Ok(());
+    };
+
+    let revisions: String = matches
+        .iter()
+        .map(|(date, revision)| revision.summary(&channel, *date))
+        .collect();
+    info!(
+        "'{}' revisions containing {}:\n{}",
+        channel, wanted, revisions
+    );
+
+    if install {
+        let description = DistToolchainDescription::from_str(&format!("{channel}-{newest}"))?;
+        let toolchain = Toolchain::from_path(&description.to_string());
+        install_toolchain(
+            &description,
+            &description,
+            None,
+            &toolchain.selected_components()?,
+        )?;
+    }
+    Ok(())
+}
@@ -1,11 +1,14 @@
 use crate::{
-    channel::Channel,
+    channel::{Channel, Package},
+    channel_diff::ChannelDiff,
+    commands::update::UpdateCommand,
     config::Config,
     fmt::{bold, colored_bold},
     path::warn_existing_fuel_executables,
     toolchain::{DistToolchainDescription, Toolchain},
 };
 use anyhow::{bail, Result};
+use std::collections::BTreeMap;
 use std::io::Write;
 use std::str::FromStr;
 use termcolor::Color;
@@ -14,8 +17,10 @@ use tracing::info;
 const UPDATED: &str = "updated";
 const PARTIALLY_UPDATED: &str = "partially updated";
 const UNCHANGED: &str = "unchanged";
+const UPDATE_AVAILABLE: &str = "update available";
 
-pub fn update() -> Result<()> {
+pub fn update(command: UpdateCommand) -> Result<()> {
+    let UpdateCommand { preview } = command;
     let config = Config::from_env()?;
     let toolchains = config.list_dist_toolchains()?;
     let mut summary: Vec<(String, String)> = Vec::with_capacity(toolchains.len());
@@ -27,15 +32,62 @@ pub fn update() -> Result<()> {
         let mut errored_bins = String::new();
 
         let description = DistToolchainDescription::from_str(&toolchain)?;
-        info!("updating the '{}' toolchain", description);
+        match preview {
+            true => info!("checking the '{}' toolchain for updates", description),
+            false => info!("updating the '{}' toolchain", description),
+        }
+
+        // Components installed outside of the selected ones, eg. with another profile, are
+        // updated as well.
+        let installed = Toolchain::from_path(&description.to_string());
+        let mut profile = installed.selected_components()?;
 
-        let (cfgs, hash) = if let Ok((channel, hash)) = Channel::from_dist_channel(&description) {
-            if let Ok(true) = config.hash_matches(&description, &hash) {
+        let (cfgs, hash) = if let Ok(published) = Channel::from_dist_channel(&description) {
+            profile.components.extend(
+                published
+                    .0
+                    .pkg
+                    .keys()
+                    .filter(|name| installed.has_component(name))
+                    .cloned(),
+            );
+
+            if let Ok(true) = config.hash_matches(&description, &published.1) {
                 info!("'{}' already installed and up to date", description);
                 summary.push((format!("{toolchain} {UNCHANGED}"), "".to_string()));
                 continue;
             };
-            (channel.build_download_configs(), hash)
+
+            let in_profile = |pkg: BTreeMap<String, Package>| -> BTreeMap<String, Package> {
+                pkg.into_iter()
+                    .filter(|(name, _)| profile.includes(name))
+                    .collect()
+            };
+            let changes = ChannelDiff::installed_packages(&description, Some(&published))
+                .ok()
+                .map(|installed| {
+                    ChannelDiff::new(&in_profile(installed), &in_profile(published.0.pkg.clone()))
+                });
+            if preview {
+                match changes {
+                    // The channel was published again without changing any of the components.
+                    Some(changes) if changes.is_empty() => summary.push((
+                        format!("{toolchain} {UNCHANGED}\n"),
+                        "  republished with the same components\n".to_string(),
+                    )),
+                    changes => summary.push((
+                        format!("{toolchain} {UPDATE_AVAILABLE}\n"),
+                        changes.map(|c| c.to_string()).unwrap_or_default(),
+                    )),
+                }
+                continue;
+            }
+            if let Some(changes) = changes.filter(|c| !c.is_empty()) {
+                info!("changes:\n{}", changes.to_string().trim_end());
+            }
+
+            let (channel, hash) = published;
+            (channel.build_download_configs(&profile), hash)
         } else {
             bail!("Could not build download configs from channel")
         };
@@ -61,7 +113,7 @@ pub fn update() -> Result<()> {
         }
 
         if errored_bins.is_empty() {
-            config.save_hash(&toolchain, &hash)?;
+            config.save_hash(&description.to_string(), &hash)?;
         } else {
             status = PARTIALLY_UPDATED.to_string();
             errored_bins = format!("  failed to update:\n{errored_bins}");
@@ -0,0 +1,70 @@
+use anyhow::Result;
+use component::Components;
+use serde::Serialize;
+use std::io::{self, Write};
+use tracing::info;
+
+use crate::{commands::which::WhichCommand, fmt::bold, proxy_cli::Resolution};
+
+#[derive(Debug, Serialize)]
+struct WhichOutput {
+    #[serde(flatten)]
+    resolution: Resolution,
+    installed: bool,
+}
+
+pub fn which(command: WhichCommand) -> Result<()> {
+    let WhichCommand {
+        executable,
+        path,
+        json,
+    } = command;
+
+    let resolve = || {
+        if executable.starts_with("forc-")
+            && !Components::collect_plugin_executables()?.contains(&executable)
+        {
+            Resolution::resolve_unmanaged_plugin(&executable, None, path.as_deref())
+        } else {
+            Resolution::resolve(&executable, None, path.as_deref())
+        }
+    };
+    let resolution = match json {
+        // Warnings raised while resolving, eg. about a stale lock file, go to stderr instead, so
+        // that the JSON output can be parsed as a whole.
+        true => {
+            let format = tracing_subscriber::fmt::format()
+                .without_time()
+                .with_level(false)
+                .with_target(false);
+            let stderr = tracing_subscriber::fmt()
+                .event_format(format)
+                .with_writer(io::stderr)
+                .finish();
+            tracing::subscriber::with_default(stderr, resolve)?
+        }
+        false => resolve()?,
+    };
+    let installed = resolution.path.is_file();
+
+    if json {
+        let output = WhichOutput {
+            resolution,
+            installed,
+        };
+        info!("{}", serde_json::to_string_pretty(&output)?);
+        return Ok(());
+    }
+
+    if installed {
+        info!("{}", resolution.path.display());
+    } else {
+        info!("{} (not installed)", resolution.path.display());
+    }
+    bold(|s| write!(s, "toolchain: "));
+    info!("{}", resolution.toolchain);
+    bold(|s| write!(s, "reason: "));
+    info!("{}", resolution.reason);
+
+    Ok(())
+}
@@ -1,8 +1,15 @@
+pub mod fuelup_bisect;
+pub mod fuelup_channel;
 pub mod fuelup_check;
 pub mod fuelup_completions;
 pub mod fuelup_component;
 pub mod fuelup_default;
+pub mod fuelup_lock;
+pub mod fuelup_override;
+pub mod fuelup_run;
 pub mod fuelup_self;
+pub mod fuelup_set;
 pub mod fuelup_show;
 pub mod fuelup_toolchain;
 pub mod fuelup_update;
+pub mod fuelup_which;
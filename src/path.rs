@@ -9,12 +9,17 @@ use tracing::warn;
 
 use dirs;
 
-use crate::constants::FUEL_TOOLCHAIN_TOML_FILE;
+use crate::constants::{
+    FUELUP_HOME, FUELUP_PROJECT_ROOT, FUEL_TOOLCHAIN_TOML_FILE, REVISION_INDEX_FILE_NAME,
+};
 
 pub const FUELUP_DIR: &str = ".fuelup";
 
 pub fn fuelup_dir() -> PathBuf {
-    dirs::home_dir().unwrap().join(FUELUP_DIR)
+    match env::var_os(FUELUP_HOME) {
+        Some(fuelup_home) => PathBuf::from(fuelup_home),
+        None => dirs::home_dir().unwrap().join(FUELUP_DIR),
+    }
 }
 
 pub fn fuelup_bin_dir() -> PathBuf {
@@ -29,6 +34,19 @@ pub fn settings_file() -> PathBuf {
     fuelup_dir().join("settings.toml")
 }
 
+// The last published channel index fuelup has fetched.
+pub fn channel_index_file() -> PathBuf {
+    fuelup_dir().join("channels.toml")
+}
+
+// The last fetched revision index of a channel, mirroring its location on gh-pages.
+pub fn revision_index_file(channel: &str) -> PathBuf {
+    fuelup_dir()
+        .join("channels")
+        .join(channel)
+        .join(REVISION_INDEX_FILE_NAME)
+}
+
 pub fn hashes_dir() -> PathBuf {
     fuelup_dir().join("hashes")
 }
@@ -41,6 +59,10 @@ pub fn store_dir() -> PathBuf {
     fuelup_dir().join("store")
 }
 
+pub fn git_checkouts_dir() -> PathBuf {
+    fuelup_dir().join("git")
+}
+
 pub fn fuelup_tmp_dir() -> PathBuf {
     fuelup_dir().join("tmp")
 }
@@ -126,7 +148,32 @@ pub fn warn_existing_fuel_executables() -> Result<()> {
     Ok(())
 }
 
+/// Finds an executable in PATH, skipping fuelup's own bin dir since it only contains proxies.
+pub fn find_executable_in_path(name: &str) -> Option<PathBuf> {
+    executable_search_dirs()
+        .into_iter()
+        .map(|dir| dir.join(name))
+        .find(|path| is_executable(path))
+}
+
+/// The directories within PATH, excluding fuelup's own bin dir.
+pub fn executable_search_dirs() -> Vec<PathBuf> {
+    let fuelup_bin_dir = fuelup_bin_dir();
+    env::var_os("PATH")
+        .map(|paths| {
+            env::split_paths(&paths)
+                .filter(|dir| *dir != fuelup_bin_dir)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
 fn find_parent_dir_with_file(starter_path: &Path, file_name: &str) -> Option<PathBuf> {
+    // An empty path, eg. the parent of a bare file name, stands for the current directory.
+    let starter_path = match starter_path.as_os_str().is_empty() {
+        true => Path::new("."),
+        false => starter_path,
+    };
     let mut path = std::fs::canonicalize(starter_path).ok()?;
     let empty_path = PathBuf::from("/");
     while path != empty_path {
@@ -142,9 +189,18 @@ fn find_parent_dir_with_file(starter_path: &Path, file_name: &str) -> Option<Pat
     None
 }
 
+/// Searches for a 'fuel-toolchain.toml' from the project root given by `FUELUP_PROJECT_ROOT`, or
+/// from the current working directory if it is not set.
 pub fn get_fuel_toolchain_toml() -> Option<PathBuf> {
-    let parent_dir =
-        find_parent_dir_with_file(&std::env::current_dir().unwrap(), FUEL_TOOLCHAIN_TOML_FILE);
+    match env::var_os(FUELUP_PROJECT_ROOT) {
+        Some(project_root) => find_fuel_toolchain_toml(Path::new(&project_root)),
+        None => find_fuel_toolchain_toml(&env::current_dir().unwrap()),
+    }
+}
+
+/// Searches for a 'fuel-toolchain.toml' within `dir` and its parents.
+pub fn find_fuel_toolchain_toml(dir: &Path) -> Option<PathBuf> {
+    let parent_dir = find_parent_dir_with_file(dir, FUEL_TOOLCHAIN_TOML_FILE);
     parent_dir.map(|p| p.join(FUEL_TOOLCHAIN_TOML_FILE))
 }
 
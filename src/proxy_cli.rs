@@ -1,104 +1,552 @@
-use anyhow::Result;
+use anyhow::{bail, Result};
+use semver::{Version, VersionReq};
+use serde::Serialize;
+use std::env;
 use std::ffi::OsString;
-use std::io::{Error, ErrorKind};
+use std::fmt;
+use std::io::{self, Error, ErrorKind, IsTerminal};
 use std::os::unix::prelude::CommandExt;
+use std::path::{Path, PathBuf};
 use std::process::{Command, ExitCode, Stdio};
-use std::str::FromStr;
-use std::{env, io};
+use tracing::info;
 
+use crate::constants::{
+    FUELUP_HOME, FUELUP_TOOLCHAIN, FUELUP_TOOLCHAIN_BIN_DIR, FUELUP_TOOLCHAIN_FILE,
+};
 use crate::download::DownloadCfg;
+use crate::git_checkout::GitCheckout;
+use crate::path::{find_executable_in_path, fuelup_dir, is_executable, settings_file};
+use crate::settings::{AutoInstall, SettingsFile};
 use crate::store::Store;
 use crate::target_triple::TargetTriple;
 use crate::toolchain::{DistToolchainDescription, Toolchain};
-use crate::toolchain_override::ToolchainOverride;
+use crate::toolchain_lock::ToolchainLock;
+use crate::toolchain_override::{ComponentSpec, ToolchainOverride};
 use component::Components;
 
-/// Runs forc or fuel-core in proxy mode
-pub fn proxy_run(arg0: &str) -> Result<ExitCode> {
-    let cmd_args: Vec<_> = env::args_os().skip(1).collect();
-    let toolchain = Toolchain::from_settings()?;
+/// The reason a toolchain, or a specific component version, was chosen for a proxied executable.
+#[derive(Debug, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Reason {
+    /// Selected with a leading `+<toolchain>` argument.
+    Selector,
+    /// Selected through the `FUELUP_TOOLCHAIN` environment variable, eg. by `fuelup run`.
+    Environment,
+    /// Declared within the [toolchain] table of a 'fuel-toolchain.toml'.
+    Override { path: PathBuf },
+    /// Pinned within the [components] table of a 'fuel-toolchain.toml', either exactly or as the
+    /// highest version matching a requirement.
+    ComponentPin {
+        path: PathBuf,
+        version: Version,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        requirement: Option<VersionReq>,
+    },
+    /// Declared within the [components] table of a 'fuel-toolchain.toml' as a path or git revision
+    /// to build from.
+    ComponentSource { path: PathBuf, source: String },
+    /// The default toolchain set in the fuelup settings.
+    Default,
+}
 
-    if !cmd_args.is_empty() {
-        let plugin = format!("{}-{}", arg0, &cmd_args[0].to_string_lossy());
-        if Components::collect_plugin_executables()?.contains(&plugin) {
-            direct_proxy(&plugin, &cmd_args[1..], &toolchain)?;
+impl fmt::Display for Reason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Reason::Selector => write!(f, "selected with '+<toolchain>'"),
+            Reason::Environment => write!(f, "selected by the {FUELUP_TOOLCHAIN} env var"),
+            Reason::Override { path } => write!(f, "overridden by {}", path.display()),
+            Reason::ComponentPin {
+                path,
+                version,
+                requirement: None,
+            } => write!(f, "version {version} pinned in {}", path.display()),
+            Reason::ComponentPin {
+                path,
+                version,
+                requirement: Some(req),
+            } => write!(
+                f,
+                "version {version} matching '{req}' in {}",
+                path.display()
+            ),
+            Reason::ComponentSource { path, source } => {
+                write!(f, "built from {source} as declared in {}", path.display())
+            }
+            Reason::Default => write!(f, "default toolchain"),
         }
     }
+}
 
-    direct_proxy(arg0, &cmd_args, &toolchain)?;
-    Ok(ExitCode::SUCCESS)
+/// The executable a proxied call resolves to, along with how it was chosen.
+///
+/// Resolving does not install anything, so this may be inspected (eg. by `fuelup which`) before
+/// deciding to run the executable; see `Resolution::install_missing()`.
+#[derive(Debug, Serialize)]
+pub struct Resolution {
+    pub path: PathBuf,
+    pub toolchain: String,
+    pub reason: Reason,
+    #[serde(skip)]
+    toolchain_bin_dir: PathBuf,
+    #[serde(skip)]
+    description: Option<DistToolchainDescription>,
+    #[serde(skip)]
+    pinned_component: Option<PinnedComponent>,
+    #[serde(skip)]
+    lock: Option<ToolchainLock>,
 }
 
-fn direct_proxy(proc_name: &str, args: &[OsString], toolchain: &Toolchain) -> Result<ExitCode> {
-    let toolchain_override: Option<ToolchainOverride> = ToolchainOverride::from_project_root();
-
-    let (bin_path, toolchain_name) = match toolchain_override {
-        Some(to) => {
-            // unwrap() is safe here since we try DistToolchainDescription::from_str()
-            // when deserializing from the toml.
-            let description =
-                DistToolchainDescription::from_str(&to.cfg.toolchain.channel.to_string()).unwrap();
-            let toolchain = Toolchain::from_path(&description.to_string());
-
-            // Install the entire toolchain declared in [toolchain] if it does not exist.
-            toolchain.install_if_nonexistent(&description)?;
-
-            // Plugins distributed by forc have to be handled a little differently,
-            // if one of them is called we want to check for 'forc' instead.
-            let component_name = if Components::is_distributed_by_forc(proc_name) {
-                component::FORC
-            } else {
-                proc_name
-            };
+/// Where a component declared within the [components] table of a 'fuel-toolchain.toml' comes from.
+#[derive(Debug)]
+enum PinnedComponent {
+    Store { name: String, version: Version },
+    Path { name: String, dir: PathBuf },
+    Git(GitCheckout),
+}
+
+impl Resolution {
+    /// Resolves `proc_name` the same way the proxy does, without executing or installing anything.
+    ///
+    /// `project_dir` is where to look for a 'fuel-toolchain.toml' from, instead of the project root
+    /// given by `FUELUP_PROJECT_ROOT` or the current working directory.
+    pub fn resolve(
+        proc_name: &str,
+        selector: Option<&str>,
+        project_dir: Option<&Path>,
+    ) -> Result<Self> {
+        let (toolchain, description, reason) = match selector {
+            Some(name) => {
+                let (toolchain, description) = Toolchain::from_selector(name)?;
+                (toolchain, description, Reason::Selector)
+            }
+            None => match env::var(FUELUP_TOOLCHAIN) {
+                Ok(name) => {
+                    // Set by a parent proxy that resolved its toolchain through a
+                    // 'fuel-toolchain.toml', which keeps applying to the whole process tree
+                    // regardless of the working directory of nested processes.
+                    if let Some(path) = env::var_os(FUELUP_TOOLCHAIN_FILE) {
+                        let to = ToolchainOverride::from_path(PathBuf::from(path))?;
+                        return Self::resolve_override(proc_name, to);
+                    }
+
+                    let (mut toolchain, description) = Toolchain::from_selector(&name)?;
+                    if let Some(bin_dir) = env::var_os(FUELUP_TOOLCHAIN_BIN_DIR) {
+                        toolchain.bin_path = PathBuf::from(bin_dir);
+                    }
+                    (toolchain, description, Reason::Environment)
+                }
+                Err(_) => match project_dir.map_or_else(
+                    ToolchainOverride::from_project_root,
+                    ToolchainOverride::from_project_dir,
+                ) {
+                    Some(to) => return Self::resolve_override(proc_name, to),
+                    None => (Toolchain::from_settings()?, None, Reason::Default),
+                },
+            },
+        };
+
+        Ok(Self {
+            path: toolchain.bin_path.join(proc_name),
+            toolchain: toolchain.name,
+            reason,
+            toolchain_bin_dir: toolchain.bin_path,
+            description,
+            pinned_component: None,
+            lock: None,
+        })
+    }
+
+    fn resolve_override(proc_name: &str, to: ToolchainOverride) -> Result<Self> {
+        let description = to.cfg.toolchain.description();
+        let toolchain = Toolchain::from_path(&to.cfg.toolchain.toolchain_name());
+        // Unlike distributable toolchains, custom toolchains can't be installed on demand.
+        if description.is_none() && !toolchain.exists() {
+            bail!(
+                "toolchain '{}' declared in {} is not installed; you may create it using 'fuelup toolchain new {}'",
+                toolchain.name,
+                to.path.display(),
+                toolchain.name
+            );
+        }
+
+        // Plugins distributed by forc have to be handled a little differently,
+        // if one of them is called we want to check for 'forc' instead.
+        let component_name = if Components::is_distributed_by_forc(proc_name) {
+            component::FORC
+        } else {
+            proc_name
+        };
+
+        let lock = ToolchainLock::for_override(&to)?;
+        let (path, reason, pinned_component) = match to.get_component_spec(component_name) {
+            Some(ComponentSpec::Path(dir)) => {
+                // Relative paths are relative to the 'fuel-toolchain.toml' declaring them.
+                let dir = to
+                    .path
+                    .parent()
+                    .map_or(dir.clone(), |parent| parent.join(dir));
+                let reason = Reason::ComponentSource {
+                    path: to.path.clone(),
+                    source: dir.display().to_string(),
+                };
+                let pinned = PinnedComponent::Path {
+                    name: component_name.to_string(),
+                    dir: dir.clone(),
+                };
+                (dir.join(proc_name), reason, Some(pinned))
+            }
+            Some(spec @ ComponentSpec::Git { url, rev }) => {
+                let checkout = GitCheckout::new(component_name, url, rev);
+                let reason = Reason::ComponentSource {
+                    path: to.path.clone(),
+                    source: spec.to_string(),
+                };
+                (
+                    checkout.bin_dir().join(proc_name),
+                    reason,
+                    Some(PinnedComponent::Git(checkout)),
+                )
+            }
+            Some(spec) => {
+                let version = match lock.as_ref().and_then(|l| l.locked_version(component_name)) {
+                    Some(version) => version.clone(),
+                    None => spec.resolve(component_name)?,
+                };
+                let requirement = match spec {
+                    ComponentSpec::VersionReq(req) => Some(req.clone()),
+                    _ => None,
+                };
+                let reason = Reason::ComponentPin {
+                    path: to.path.clone(),
+                    version: version.clone(),
+                    requirement,
+                };
+                let path = Store::from_env()?
+                    .component_dir_path(component_name, &version)
+                    .join(proc_name);
+                let pinned = PinnedComponent::Store {
+                    name: component_name.to_string(),
+                    version,
+                };
+                (path, reason, Some(pinned))
+            }
+            None => (
+                toolchain.bin_path.join(proc_name),
+                Reason::Override {
+                    path: to.path.clone(),
+                },
+                None,
+            ),
+        };
+
+        Ok(Self {
+            path,
+            toolchain: toolchain.name,
+            reason,
+            toolchain_bin_dir: toolchain.bin_path,
+            description,
+            pinned_component,
+            lock,
+        })
+    }
+
+    /// Resolves a `forc-*` plugin that is not managed by fuelup. Such plugins are looked up within
+    /// the resolved toolchain first, and then within PATH.
+    pub fn resolve_unmanaged_plugin(
+        plugin: &str,
+        selector: Option<&str>,
+        project_dir: Option<&Path>,
+    ) -> Result<Self> {
+        let mut resolution = Self::resolve(plugin, selector, project_dir)?;
+        if !is_executable(&resolution.path) {
+            if let Some(path) = find_executable_in_path(plugin) {
+                resolution.path = path;
+            }
+        }
+        Ok(resolution)
+    }
+
+    /// Installs the resolved distributable toolchain, pinned component, or component missing from
+    /// a custom toolchain, as allowed by the `auto-install` setting, ie. `auto_install` within the
+    /// settings file.
+    pub fn install_missing(&self) -> Result<()> {
+        let policy =
+            SettingsFile::new(settings_file()).with(|s| Ok(s.auto_install.unwrap_or_default()))?;
+        let toolchain = Toolchain::from_path(&self.toolchain);
+
+        if let Some(description) = &self.description {
+            if !toolchain.exists() {
+                confirm_install(
+                    policy,
+                    &format!("toolchain '{description}'"),
+                    &format!("fuelup toolchain install {description}"),
+                )?;
+                toolchain.install_if_nonexistent(description, self.lock.as_ref())?;
+            }
+        }
+
+        match &self.pinned_component {
             // Install components within [components] that are declared but missing from the store.
-            if let Some(version) = to.get_component_version(component_name) {
+            Some(PinnedComponent::Store { name, version }) => {
                 let store = Store::from_env()?;
 
-                if !store.has_component(component_name, version) {
-                    let download_cfg = DownloadCfg::new(
-                        component_name,
-                        TargetTriple::from_component(component_name)?,
-                        Some(version.clone()),
+                if !store.has_component(name, version) {
+                    confirm_install(
+                        policy,
+                        &format!("{name} {version}"),
+                        "fuelup toolchain install --from-file",
                     )?;
+                    let download_cfg =
+                        ToolchainLock::pinned_download_cfg(self.lock.as_ref(), name, version)?;
+                    if let Reason::ComponentPin {
+                        path,
+                        requirement: Some(req),
+                        ..
+                    } = &self.reason
+                    {
+                        info!(
+                            "selected {name} {version} for '{req}' in {}",
+                            path.display()
+                        );
+                    }
                     store.install_component(&download_cfg)?;
                 };
+                return Ok(());
+            }
+            // Binaries within a path are built by the developer, so there is nothing to install.
+            Some(PinnedComponent::Path { name, dir }) => {
+                if !self.path.exists() {
+                    bail!(
+                        "'{}' not found in {}, the path declared for {name}; you may need to build it first",
+                        self.path.file_name().unwrap_or_default().to_string_lossy(),
+                        dir.display()
+                    );
+                }
+                return Ok(());
+            }
+            Some(PinnedComponent::Git(checkout)) => {
+                if !self.path.exists() {
+                    confirm_install(
+                        policy,
+                        &format!(
+                            "{} from {}#{}",
+                            checkout.component, checkout.url, checkout.rev
+                        ),
+                        "fuelup toolchain install --from-file",
+                    )?;
+                    checkout.build()?;
+                }
+                return Ok(());
+            }
+            None => {}
+        }
 
-                (
-                    store
-                        .component_dir_path(component_name, version)
-                        .join(proc_name),
-                    description.to_string(),
-                )
-            } else {
-                (
-                    toolchain.bin_path.join(component_name),
-                    description.to_string(),
-                )
+        // Components are only added to custom toolchains on demand; distributable toolchains are
+        // installed as a whole.
+        if self.description.is_none() && toolchain.exists() && !self.path.exists() {
+            if let Some(component) = self.missing_component()? {
+                let fix = if Toolchain::from_settings()?.name == toolchain.name {
+                    format!("fuelup component add {component}")
+                } else {
+                    format!(
+                        "fuelup default {} && fuelup component add {component}",
+                        toolchain.name
+                    )
+                };
+                confirm_install(
+                    policy,
+                    &format!("component '{component}' in toolchain '{}'", toolchain.name),
+                    &fix,
+                )?;
+                let download_cfg =
+                    DownloadCfg::new(&component, TargetTriple::from_component(&component)?, None)?;
+                toolchain.add_component(download_cfg)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The name of the component providing the resolved executable, if it is managed by fuelup.
+    fn missing_component(&self) -> Result<Option<String>> {
+        let Some(executable) = self.path.file_name().and_then(|name| name.to_str()) else {
+            return Ok(None);
+        };
+        if Components::is_distributed_by_forc(executable) {
+            return Ok(Some(component::FORC.to_string()));
+        }
+
+        Ok(Components::collect()?
+            .component
+            .into_values()
+            .find(|c| c.executables.iter().any(|e| e == executable))
+            .map(|c| c.name))
+    }
+
+    /// Exports the resolution into the environment of a child process, so that nested proxied
+    /// calls within the whole process tree use the same toolchain.
+    fn export(&self, cmd: &mut Command) {
+        cmd.env(FUELUP_TOOLCHAIN, &self.toolchain)
+            .env(FUELUP_TOOLCHAIN_BIN_DIR, &self.toolchain_bin_dir)
+            .env(FUELUP_HOME, fuelup_dir());
+
+        match &self.reason {
+            Reason::Override { path }
+            | Reason::ComponentPin { path, .. }
+            | Reason::ComponentSource { path, .. } => {
+                cmd.env(FUELUP_TOOLCHAIN_FILE, path);
+            }
+            _ => {
+                cmd.env_remove(FUELUP_TOOLCHAIN_FILE);
+            }
+        }
+    }
+}
+
+/// Runs forc or fuel-core in proxy mode
+pub fn proxy_run(arg0: &str) -> Result<ExitCode> {
+    let mut cmd_args: Vec<_> = env::args_os().skip(1).collect();
+
+    // Similar to rustup's `cargo +nightly`, a leading `+<toolchain>` argument selects the
+    // toolchain to run with, taking precedence over both the default toolchain and any override.
+    let selector = parse_toolchain_selector(&cmd_args);
+    if selector.is_some() {
+        cmd_args.remove(0);
+    }
+
+    // Editors and other tooling may run forc from anywhere, so a project given on the command line
+    // takes precedence over the working directory when looking for a 'fuel-toolchain.toml'.
+    let project_dir = if arg0.starts_with(component::FORC) {
+        parse_project_dir(&cmd_args)
+    } else {
+        None
+    };
+    let project_dir = project_dir.as_deref();
+
+    if let Some(subcommand) = cmd_args.first().and_then(|arg| arg.to_str()) {
+        let plugin = format!("{arg0}-{subcommand}");
+        if Components::collect_plugin_executables()?.contains(&plugin) {
+            direct_proxy(&plugin, &cmd_args[1..], selector.as_deref(), project_dir)?;
+        } else if arg0 == component::FORC && is_plugin_subcommand(subcommand) {
+            // Plugins that fuelup does not know about, eg. in-house ones dropped into a custom
+            // toolchain or installed with cargo, are run if they can be found. forc is resolved
+            // first, as it runs the subcommand otherwise.
+            let forc = Resolution::resolve(arg0, selector.as_deref(), project_dir)?;
+            if unmanaged_plugin_exists(&plugin, &forc.toolchain_bin_dir) {
+                let resolution = Resolution::resolve_unmanaged_plugin(
+                    &plugin,
+                    selector.as_deref(),
+                    project_dir,
+                )?;
+                if resolution.path.is_file() {
+                    resolution.install_missing()?;
+                    exec(resolution, &cmd_args[1..], &plugin)?;
+                }
+            }
+            forc.install_missing()?;
+            return exec(forc, &cmd_args, arg0);
+        }
+    }
+
+    direct_proxy(arg0, &cmd_args, selector.as_deref(), project_dir)?;
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Finds the project directory given through `--path`, `-p` or `--manifest-path`, if any.
+fn parse_project_dir(args: &[OsString]) -> Option<PathBuf> {
+    let mut args = args.iter().map_while(|arg| arg.to_str());
+    while let Some(arg) = args.next() {
+        let path = match arg {
+            "--" => return None,
+            "--path" | "-p" | "--manifest-path" => args.next(),
+            _ => arg
+                .strip_prefix("--path=")
+                .or_else(|| arg.strip_prefix("--manifest-path=")),
+        };
+
+        if let Some(path) = path.map(PathBuf::from) {
+            // '--manifest-path' may point to the manifest itself rather than its directory.
+            if path.is_file() {
+                return path.parent().map(Path::to_path_buf);
+            }
+            // A project that doesn't exist yet, eg. for 'forc new', has no toolchain of its own.
+            return path.is_dir().then_some(path);
+        }
+    }
+    None
+}
+
+/// Checks whether `policy` allows installing `missing`, failing with `fix`, the command that would
+/// install it, if it does not.
+fn confirm_install(policy: AutoInstall, missing: &str, fix: &str) -> Result<()> {
+    let install = match policy {
+        AutoInstall::Always => true,
+        AutoInstall::Never => false,
+        AutoInstall::Prompt => {
+            io::stdin().is_terminal() && {
+                eprint!("{missing} is not installed. Install it now? [y/N] ");
+                let mut answer = String::new();
+                io::stdin().read_line(&mut answer)?;
+                matches!(answer.trim(), "y" | "Y" | "yes")
             }
         }
-        None => (
-            toolchain.bin_path.join(proc_name),
-            toolchain.name.to_owned(),
-        ),
     };
 
-    let mut cmd = Command::new(bin_path);
+    if !install {
+        bail!("{missing} is not installed; you may install it using '{fix}'");
+    }
+    Ok(())
+}
+
+fn is_plugin_subcommand(subcommand: &str) -> bool {
+    !subcommand.is_empty() && !subcommand.starts_with('-') && !subcommand.contains('/')
+}
+
+// Whether an unmanaged plugin exists within the bin directory of the resolved toolchain or PATH,
+// before resolving the plugin itself for every forc subcommand, most of which aren't plugins.
+fn unmanaged_plugin_exists(plugin: &str, toolchain_bin_dir: &Path) -> bool {
+    let in_toolchain = is_executable(&toolchain_bin_dir.join(plugin));
+    let in_bin_dir = env::var_os(FUELUP_TOOLCHAIN_BIN_DIR)
+        .is_some_and(|dir| is_executable(&PathBuf::from(dir).join(plugin)));
+    in_toolchain || in_bin_dir || find_executable_in_path(plugin).is_some()
+}
+
+fn parse_toolchain_selector(args: &[OsString]) -> Option<String> {
+    args.first()
+        .and_then(|arg| arg.to_str())
+        .and_then(|arg| arg.strip_prefix('+'))
+        .map(String::from)
+}
+
+fn direct_proxy(
+    proc_name: &str,
+    args: &[OsString],
+    selector: Option<&str>,
+    project_dir: Option<&Path>,
+) -> Result<ExitCode> {
+    let resolution = Resolution::resolve(proc_name, selector, project_dir)?;
+    resolution.install_missing()?;
+
+    exec(resolution, args, proc_name)
+}
+
+fn exec(resolution: Resolution, args: &[OsString], proc_name: &str) -> Result<ExitCode> {
+    let mut cmd = Command::new(&resolution.path);
 
     cmd.args(args);
     cmd.stdin(Stdio::inherit());
+    resolution.export(&mut cmd);
 
-    return exec(&mut cmd, proc_name, &toolchain_name).map_err(anyhow::Error::from);
-
-    fn exec(cmd: &mut Command, proc_name: &str, toolchain_name: &str) -> io::Result<ExitCode> {
-        let error = cmd.exec();
-        match error.kind() {
-            ErrorKind::NotFound => Err(Error::new(
-                ErrorKind::NotFound,
-                format!(
-                    "component '{proc_name}' not found in currently active toolchain '{toolchain_name}'"
-                ),
-            )),
-            _ => Err(error),
-        }
+    let error = cmd.exec();
+    match error.kind() {
+        ErrorKind::NotFound => Err(Error::new(
+            ErrorKind::NotFound,
+            format!(
+                "component '{proc_name}' not found in currently active toolchain '{}'",
+                resolution.toolchain
+            ),
+        )
+        .into()),
+        _ => Err(error.into()),
     }
 }
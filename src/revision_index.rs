@@ -0,0 +1,222 @@
+use anyhow::{bail, Result};
+use semver::Version;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::io::Read;
+use time::Date;
+use toml_edit::de;
+use tracing::warn;
+
+use crate::{
+    channel::Package,
+    channel_index::DATED_CHANNELS,
+    constants::{DATE_FORMAT, FUELUP_GH_PAGES, REVISION_INDEX_FILE_NAME},
+    file,
+    path::{ensure_dir_exists, revision_index_file},
+};
+
+// Representation of the 'index.toml' published by `build-channel` next to the dated copies of a
+// channel, eg. 'channels/latest/index.toml', recording what each revision of the channel contains.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct RevisionIndex {
+    #[serde(default)]
+    pub revision: BTreeMap<String, Revision>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Revision {
+    // sha256 of the channel TOML, as recorded in the hashes of installed toolchains.
+    pub hash: String,
+    pub pkg: BTreeMap<String, Version>,
+}
+
+impl Revision {
+    // The revision as listed by fuelup, eg. 'latest-2023-01-27' followed by its packages.
+    pub fn summary(&self, channel: &str, date: Date) -> String {
+        let mut summary = format!("{channel}-{date}\n");
+        for (name, version) in &self.pkg {
+            summary.push_str(&format!("  {name} {version}\n"));
+        }
+        summary
+    }
+
+    // The packages of the revision, without their targets.
+    pub fn packages(&self) -> BTreeMap<String, Package> {
+        self.pkg
+            .iter()
+            .map(|(name, version)| {
+                let package = Package {
+                    target: BTreeMap::new(),
+                    version: version.clone(),
+                };
+                (name.clone(), package)
+            })
+            .collect()
+    }
+}
+
+impl RevisionIndex {
+    pub fn from_toml(toml: &str) -> Result<Self> {
+        let index: RevisionIndex = de::from_str(toml)?;
+        if let Some(date) = index
+            .revision
+            .keys()
+            .find(|date| Date::parse(date, DATE_FORMAT).is_err())
+        {
+            bail!("Invalid revision date '{date}', expected YYYY-MM-DD");
+        }
+        Ok(index)
+    }
+
+    // The revisions of a channel, fetched and cached within the fuelup directory. If the index
+    // can't be fetched, or `offline` is set, the cached copy is used instead.
+    pub fn load(channel: &str, offline: bool) -> Result<Self> {
+        if !DATED_CHANNELS.contains(&channel) {
+            bail!(
+                "'{}' is not published by date and has no revisions; expected one of {}",
+                channel,
+                DATED_CHANNELS.join(", ")
+            );
+        }
+
+        let fetch_error = match offline {
+            true => None,
+            false => match Self::fetch(channel) {
+                Ok(index) => return Ok(index),
+                Err(e) => Some(e),
+            },
+        };
+
+        let cached = revision_index_file(channel);
+        match (cached.is_file(), fetch_error) {
+            (true, Some(e)) => warn!(
+                "warning: Could not fetch the revisions of '{}', using the cached copy: {}",
+                channel, e
+            ),
+            (true, None) => {}
+            (false, Some(e)) => bail!("Could not fetch the revisions of '{}': {}", channel, e),
+            (false, None) => bail!(
+                "The revisions of '{}' have not been fetched yet; run without --offline first",
+                channel
+            ),
+        }
+        Self::from_toml(&file::read_file(REVISION_INDEX_FILE_NAME, &cached)?)
+    }
+
+    fn fetch(channel: &str) -> Result<Self> {
+        let url = format!("{FUELUP_GH_PAGES}channels/{channel}/{REVISION_INDEX_FILE_NAME}");
+        let handle = ureq::builder().user_agent("fuelup").build();
+        let mut toml = String::new();
+        handle
+            .get(&url)
+            .call()?
+            .into_reader()
+            .read_to_string(&mut toml)?;
+
+        let index = Self::from_toml(&toml)?;
+        let cached = revision_index_file(channel);
+        if let Some(parent) = cached.parent() {
+            ensure_dir_exists(parent)?;
+        }
+        file::write_file(&cached, &toml)?;
+        Ok(index)
+    }
+
+    // Whether the index already records every revision published on or before `date`, ie. one
+    // since then. Revisions never change once published, so it then needn't be fetched again.
+    pub fn covers(&self, date: Date) -> bool {
+        self.between(Some(date), None).next().is_some()
+    }
+
+    // The most recent revision published on or before `date`, unless `date` predates the index,
+    // in which case the revisions published around it are unknown.
+    pub fn on_or_before(&self, date: Date) -> Option<Date> {
+        let earliest = self.between(None, None).last()?.0;
+        if date < earliest {
+            return None;
+        }
+        self.between(None, Some(date)).next().map(|(date, _)| date)
+    }
+
+    // Revisions published within the given dates, both inclusive, most recent first.
+    pub fn between(
+        &self,
+        since: Option<Date>,
+        until: Option<Date>,
+    ) -> impl Iterator<Item = (Date, &Revision)> {
+        self.revision
+            .iter()
+            .rev()
+            .filter_map(|(date, revision)| {
+                Date::parse(date, DATE_FORMAT)
+                    .ok()
+                    .map(|date| (date, revision))
+            })
+            .filter(move |(date, _)| {
+                since.is_none_or(|since| *date >= since) && until.is_none_or(|until| *date <= until)
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::macros::date;
+
+    const INDEX: &str = include_str!("../tests/revision-index-latest-example.toml");
+
+    #[test]
+    fn revisions_between() {
+        let index = RevisionIndex::from_toml(INDEX).unwrap();
+
+        let dates: Vec<Date> = index.between(None, None).map(|(d, _)| d).collect();
+        assert_eq!(
+            dates,
+            [
+                date!(2023 - 02 - 10),
+                date!(2023 - 01 - 27),
+                date!(2023 - 01 - 09)
+            ]
+        );
+
+        let revisions: Vec<_> = index
+            .between(Some(date!(2023 - 01 - 10)), Some(date!(2023 - 01 - 27)))
+            .collect();
+        assert_eq!(revisions.len(), 1);
+        assert_eq!(revisions[0].1.hash, "bbbb");
+        assert_eq!(revisions[0].1.pkg["forc"], Version::new(0, 35, 0));
+    }
+
+    #[test]
+    fn revision_on_or_before() {
+        let index = RevisionIndex::from_toml(INDEX).unwrap();
+
+        assert_eq!(
+            index.on_or_before(date!(2023 - 01 - 27)),
+            Some(date!(2023 - 01 - 27))
+        );
+        assert_eq!(
+            index.on_or_before(date!(2023 - 02 - 01)),
+            Some(date!(2023 - 01 - 27))
+        );
+        assert_eq!(
+            index.on_or_before(date!(2023 - 03 - 01)),
+            Some(date!(2023 - 02 - 10))
+        );
+        assert_eq!(index.on_or_before(date!(2023 - 01 - 01)), None);
+    }
+
+    #[test]
+    fn index_covers_date() {
+        let index = RevisionIndex::from_toml(INDEX).unwrap();
+
+        assert!(index.covers(date!(2023 - 01 - 01)));
+        assert!(index.covers(date!(2023 - 02 - 10)));
+        assert!(!index.covers(date!(2023 - 02 - 11)));
+    }
+
+    #[test]
+    fn invalid_revision_date() {
+        assert!(RevisionIndex::from_toml("[revision.latest]\nhash = \"\"\npkg = {}\n").is_err());
+    }
+}
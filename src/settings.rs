@@ -1,10 +1,12 @@
 use serde::{Deserialize, Serialize};
-use std::{cell::RefCell, path::PathBuf};
+use std::{cell::RefCell, fmt, path::PathBuf, str::FromStr};
 use toml_edit::{de, ser, Document};
 
-use anyhow::Result;
+use anyhow::{bail, Result};
+use component::{Components, Profile, DEFAULT_PROFILE};
 
 use crate::file;
+use crate::path::settings_file;
 
 pub struct SettingsFile {
     path: PathBuf,
@@ -70,6 +72,53 @@ impl SettingsFile {
 #[derive(Debug, Clone, Default, Deserialize, Serialize)]
 pub struct Settings {
     pub default_toolchain: Option<String>,
+    pub auto_install: Option<AutoInstall>,
+    pub profile: Option<String>,
+}
+
+/// Whether proxies may install missing toolchains and components before running them.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AutoInstall {
+    #[default]
+    Always,
+    Prompt,
+    Never,
+}
+
+impl fmt::Display for AutoInstall {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AutoInstall::Always => write!(f, "always"),
+            AutoInstall::Prompt => write!(f, "prompt"),
+            AutoInstall::Never => write!(f, "never"),
+        }
+    }
+}
+
+impl FromStr for AutoInstall {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "always" => Ok(AutoInstall::Always),
+            "prompt" => Ok(AutoInstall::Prompt),
+            "never" => Ok(AutoInstall::Never),
+            _ => {
+                bail!("Unknown auto-install policy '{s}' [possible values: always, prompt, never]")
+            }
+        }
+    }
+}
+
+/// The installation profile set through `fuelup set profile`, or the default one.
+pub fn default_profile() -> Result<Profile> {
+    // Reading settings would create the file, which marks the first install as done.
+    let name = match settings_file().is_file() {
+        true => SettingsFile::new(settings_file()).with(|s| Ok(s.profile.clone()))?,
+        false => None,
+    };
+    Components::profile(name.as_deref().unwrap_or(DEFAULT_PROFILE))
 }
 
 impl Settings {
@@ -143,8 +192,28 @@ mod tests {
 
         let settings = Settings {
             default_toolchain: Some("yet-another-default-toolchain".to_string()),
+            ..Default::default()
         };
 
         assert_eq!(settings.to_string().unwrap(), expected_toml);
     }
+
+    #[test]
+    fn parse_auto_install() {
+        let settings = Settings::parse("auto_install = \"prompt\"\n").unwrap();
+        assert_eq!(settings.auto_install, Some(AutoInstall::Prompt));
+        assert!(Settings::parse("auto_install = \"sometimes\"\n").is_err());
+
+        let settings = Settings {
+            auto_install: Some(AutoInstall::Never),
+            ..Default::default()
+        };
+        assert_eq!(settings.to_string().unwrap(), "auto_install = \"never\"\n");
+    }
+
+    #[test]
+    fn parse_profile() {
+        let settings = Settings::parse("profile = \"minimal\"\n").unwrap();
+        assert_eq!(settings.profile.as_deref(), Some("minimal"));
+    }
 }
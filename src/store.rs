@@ -1,3 +1,5 @@
+use std::fs;
+use std::os::unix::fs::MetadataExt;
 use std::path::{Path, PathBuf};
 
 use anyhow::Result;
@@ -32,6 +34,49 @@ impl Store {
         self.path().join(dirname).exists()
     }
 
+    // Lists the versions of a component installed within the store, in no particular order.
+    pub(crate) fn installed_versions(&self, component_name: &str) -> Result<Vec<Version>> {
+        let prefix = format!("{component_name}-");
+        let mut versions = vec![];
+        for entry in std::fs::read_dir(&self.path)? {
+            let dirname = entry?.file_name();
+            // Other components sharing the prefix, eg. 'forc-fmt-0.35.0' for 'forc', fail to parse.
+            if let Some(version) = dirname
+                .to_str()
+                .and_then(|d| d.strip_prefix(&prefix))
+                .and_then(|v| Version::parse(v).ok())
+            {
+                versions.push(version);
+            }
+        }
+        Ok(versions)
+    }
+
+    // The version of a component within the store that `executable`, eg. within a toolchain's bin
+    // dir, is linked to, if any.
+    pub(crate) fn linked_version(
+        &self,
+        component_name: &str,
+        executable: &Path,
+    ) -> Result<Option<Version>> {
+        let (Some(file_name), Ok(linked)) = (executable.file_name(), fs::metadata(executable))
+        else {
+            return Ok(None);
+        };
+        for version in self.installed_versions(component_name)? {
+            let stored = self
+                .component_dir_path(component_name, &version)
+                .join(file_name);
+            // Both hard links and symlinks resolve to the same inode.
+            if let Ok(stored) = fs::metadata(stored) {
+                if stored.dev() == linked.dev() && stored.ino() == linked.ino() {
+                    return Ok(Some(version));
+                }
+            }
+        }
+        Ok(None)
+    }
+
     pub(crate) fn component_dir_path(&self, component_name: &str, version: &Version) -> PathBuf {
         self.path.join(component_dirname(component_name, version))
     }
@@ -1,43 +1,47 @@
 use anyhow::{bail, Context, Result};
-use component::{self, Components};
+use component::{self, Components, Profile};
+use semver::Version;
+use std::collections::BTreeMap;
 use std::fmt;
 use std::fs::{remove_dir_all, remove_file};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process::Command;
 use std::str::FromStr;
 use time::Date;
+use toml_edit::{de, ser};
 use tracing::{error, info};
 
-use crate::channel::{self, is_beta_toolchain, Channel};
+use crate::channel::{self, Channel};
+use crate::channel_index::ChannelIndex;
 use crate::config::Config;
-use crate::constants::DATE_FORMAT;
+use crate::constants::{DATE_FORMAT, TOOLCHAIN_SELECTION_FILE_NAME};
 use crate::download::DownloadCfg;
-use crate::file::{hard_or_symlink_file, is_executable};
+use crate::file::{self, hard_or_symlink_file, is_executable};
 use crate::ops::fuelup_self::self_update;
 use crate::path::{
-    ensure_dir_exists, fuelup_bin, fuelup_bin_dir, fuelup_tmp_dir, settings_file,
-    toolchain_bin_dir, toolchain_dir,
+    ensure_dir_exists, executable_search_dirs, fuelup_bin, fuelup_bin_dir, fuelup_tmp_dir,
+    settings_file, toolchain_bin_dir, toolchain_dir,
 };
-use crate::settings::SettingsFile;
+use crate::revision_index::RevisionIndex;
+use crate::settings::{default_profile, SettingsFile};
 use crate::store::Store;
 use crate::target_triple::TargetTriple;
+use crate::toolchain_lock::ToolchainLock;
 
-pub const RESERVED_TOOLCHAIN_NAMES: &[&str] = &[
-    channel::LATEST,
-    channel::BETA_1,
-    channel::BETA_2,
-    channel::BETA_3,
-    channel::NIGHTLY,
-    channel::STABLE,
-];
+const PLUGIN_PREFIX: &str = "forc-";
 
-#[derive(Debug, Eq, PartialEq)]
+// The names of distributable toolchains, which can't be used as names for custom toolchains.
+pub fn reserved_toolchain_names() -> Vec<String> {
+    ChannelIndex::load().channel_names()
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
 pub enum DistToolchainName {
-    Beta1,
-    Beta2,
-    Beta3,
     Latest,
     Nightly,
+    Stable,
+    // A channel from the channel index, eg. 'beta-3'.
+    Named(String),
 }
 
 impl fmt::Display for DistToolchainName {
@@ -45,9 +49,8 @@ impl fmt::Display for DistToolchainName {
         match self {
             DistToolchainName::Latest => write!(f, "{}", channel::LATEST),
             DistToolchainName::Nightly => write!(f, "{}", channel::NIGHTLY),
-            DistToolchainName::Beta1 => write!(f, "{}", channel::BETA_1),
-            DistToolchainName::Beta2 => write!(f, "{}", channel::BETA_2),
-            DistToolchainName::Beta3 => write!(f, "{}", channel::BETA_3),
+            DistToolchainName::Stable => write!(f, "{}", channel::STABLE),
+            DistToolchainName::Named(name) => write!(f, "{name}"),
         }
     }
 }
@@ -58,15 +61,14 @@ impl FromStr for DistToolchainName {
         match s {
             channel::LATEST => Ok(Self::Latest),
             channel::NIGHTLY => Ok(Self::Nightly),
-            channel::BETA_1 => Ok(Self::Beta1),
-            channel::BETA_2 => Ok(Self::Beta2),
-            channel::BETA_3 => Ok(Self::Beta3),
+            channel::STABLE => Ok(Self::Stable),
+            _ if ChannelIndex::load().contains(s) => Ok(Self::Named(s.to_string())),
             _ => bail!("Unknown name for toolchain: {}", s),
         }
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct DistToolchainDescription {
     pub name: DistToolchainName,
     pub date: Option<Date>,
@@ -99,6 +101,54 @@ fn parse_metadata(metadata: String) -> Result<(Option<Date>, Option<TargetTriple
     }
 }
 
+impl DistToolchainDescription {
+    // The channel the toolchain is published in, eg. 'latest-2023-02-01', ie. without its target.
+    pub fn channel(&self) -> String {
+        match self.date {
+            Some(d) => format!("{}-{}", self.name, d),
+            None => self.name.to_string(),
+        }
+    }
+
+    // The revision of a dated channel current on the date of this description, ie. the most
+    // recent one published on or before it, eg. 'nightly-2023-01-07' for 'nightly-2023-01-08' if
+    // no nightly was published that day. Returns `None` if the revision index doesn't tell.
+    pub fn nearest_revision(&self) -> Option<Self> {
+        let date = self.date?;
+        let channel = self.name.to_string();
+        let index = RevisionIndex::load(&channel, true)
+            .ok()
+            .filter(|index| index.covers(date))
+            .or_else(|| RevisionIndex::load(&channel, false).ok())?;
+        self.revision_in(&index)
+    }
+
+    // The revision of a dated channel current on the date of this description, within `index`.
+    fn revision_in(&self, index: &RevisionIndex) -> Option<Self> {
+        let published = index.on_or_before(self.date?)?;
+        Some(Self {
+            date: Some(published),
+            ..self.clone()
+        })
+    }
+
+    // Resolves this description to the revision published on or before its date, reporting the
+    // revision chosen if it is not the one requested.
+    pub fn resolve_revision(self) -> Self {
+        match self.nearest_revision() {
+            Some(revision) if revision.date != self.date => {
+                info!(
+                    "'{}' was not published; using '{}', the nearest earlier revision",
+                    self.channel(),
+                    revision.channel()
+                );
+                revision
+            }
+            _ => self,
+        }
+    }
+}
+
 impl fmt::Display for DistToolchainDescription {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let target = TargetTriple::from_host().unwrap_or_default();
@@ -116,37 +166,46 @@ impl FromStr for DistToolchainDescription {
             bail!("Invalid distributable toolchain name '{}'", s);
         }
 
-        let (name, metadata) = s.split_once('-').unwrap_or((s, ""));
+        let index = ChannelIndex::load();
+        let Some((name, metadata)) = index.split_channel(s) else {
+            bail!(
+                "Unknown name for toolchain: {}; expected one of {}",
+                s,
+                index.channel_names().join(", ")
+            );
+        };
+        let name = DistToolchainName::from_str(name)?;
 
         if metadata.is_empty() {
             Ok(Self {
-                name: DistToolchainName::from_str(name)?,
+                name,
                 date: None,
                 target: TargetTriple::from_host().ok(),
             })
         } else {
             match parse_metadata(metadata.to_string()) {
-                Ok((date, target)) => Ok(Self {
-                    name: DistToolchainName::from_str(name)?,
-                    date,
-                    target,
-                }),
-                Err(e) => {
-                    if is_beta_toolchain(s) {
-                        Ok(Self {
-                            name: DistToolchainName::from_str(s)?,
-                            date: None,
-                            target: TargetTriple::from_host().ok(),
-                        })
-                    } else {
-                        bail!("Invalid toolchain metadata within input '{}' - {}", s, e)
-                    }
-                }
+                Ok((Some(_), _)) if matches!(name, DistToolchainName::Named(_)) => bail!(
+                    "Invalid toolchain metadata within input '{}' - '{}' is not published by date",
+                    s,
+                    name
+                ),
+                Ok((date, target)) => Ok(Self { name, date, target }),
+                Err(e) => bail!("Invalid toolchain metadata within input '{}' - {}", s, e),
             }
         }
     }
 }
 
+// The version an executable reports through '--version', eg. 'forc 0.35.3'.
+fn reported_version(executable: &Path) -> Option<Version> {
+    let output = Command::new(executable).arg("--version").output().ok()?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    stdout
+        .split_whitespace()
+        .last()
+        .and_then(|v| Version::parse(v).ok())
+}
+
 fn cache_sway_std_libs(forc_bin_path: PathBuf) -> Result<()> {
     let fuelup_tmp_dir = fuelup_tmp_dir();
     ensure_dir_exists(&fuelup_tmp_dir)?;
@@ -214,14 +273,63 @@ impl Toolchain {
         bail!("No default toolchain detected. Please install or create a toolchain first.")
     }
 
+    /// Resolves a toolchain selected by the user, eg. through `+<toolchain>` or `fuelup run`.
+    /// Distributable toolchains are returned along with their description since they may not be
+    /// installed yet, while custom toolchains must already exist.
+    pub fn from_selector(name: &str) -> Result<(Self, Option<DistToolchainDescription>)> {
+        match DistToolchainDescription::from_str(name) {
+            Ok(description) => Ok((Self::from_path(&description.to_string()), Some(description))),
+            Err(_) => {
+                let toolchain = Self::from_path(name);
+                if !toolchain.exists() {
+                    bail!(
+                        "toolchain '{name}' is not installed; you may create it using 'fuelup toolchain new {name}'"
+                    );
+                }
+                Ok((toolchain, None))
+            }
+        }
+    }
+
     pub fn is_distributed(&self) -> bool {
-        RESERVED_TOOLCHAIN_NAMES.contains(&self.name.split_once('-').unwrap_or((&self.name, "")).0)
+        ChannelIndex::load().split_channel(&self.name).is_some()
     }
 
     pub fn exists(&self) -> bool {
         self.path.exists() && self.path.is_dir()
     }
 
+    /// The components selected through '--component' or '--exclude' when installing the
+    /// toolchain, if any.
+    pub fn selection(&self) -> Result<Option<Profile>> {
+        let selection_file = self.path.join(TOOLCHAIN_SELECTION_FILE_NAME);
+        if !selection_file.is_file() {
+            return Ok(None);
+        }
+        let toml = file::read_file(TOOLCHAIN_SELECTION_FILE_NAME, &selection_file)?;
+        Ok(Some(de::from_str(&toml)?))
+    }
+
+    /// Remembers the components selected for the toolchain, or forgets them given `None`.
+    pub fn save_selection(&self, selection: Option<&Profile>) -> Result<()> {
+        let selection_file = self.path.join(TOOLCHAIN_SELECTION_FILE_NAME);
+        match selection {
+            Some(selection) => file::write_file(&selection_file, &ser::to_string(selection)?)?,
+            None if selection_file.is_file() => remove_file(selection_file)?,
+            None => {}
+        }
+        Ok(())
+    }
+
+    /// The components the toolchain is meant to provide: those selected when installing it, or
+    /// otherwise the ones of the default profile.
+    pub fn selected_components(&self) -> Result<Profile> {
+        match self.selection()? {
+            Some(selection) => Ok(selection),
+            None => default_profile(),
+        }
+    }
+
     pub fn has_component(&self, component: &str) -> bool {
         if let Some(component) = Components::collect()
             .expect("Failed to collect components")
@@ -237,6 +345,57 @@ impl Toolchain {
         }
     }
 
+    /// The version of each published component installed within the toolchain, as linked from the
+    /// store or otherwise reported by its executable through '--version'. Components whose version
+    /// can't be determined map to `None`.
+    pub fn component_versions(&self) -> Result<BTreeMap<String, Option<Version>>> {
+        let store = Store::from_env()?;
+        let mut versions = BTreeMap::new();
+        for component in Components::collect_publishables()? {
+            if !self.has_component(&component.name) {
+                continue;
+            }
+            let Some(executable) = component.executables.first() else {
+                continue;
+            };
+            let executable = self.bin_path.join(executable);
+            let version = match store.linked_version(&component.name, &executable)? {
+                Some(version) => Some(version),
+                None => reported_version(&executable),
+            };
+            versions.insert(component.name, version);
+        }
+        Ok(versions)
+    }
+
+    /// Collects `forc-*` plugins that are not managed by fuelup, looking within the toolchain's bin
+    /// dir first and then PATH, similar to how cargo discovers `cargo-*` subcommands.
+    pub fn unmanaged_plugins(&self) -> Result<BTreeMap<String, PathBuf>> {
+        let managed: Vec<String> = Components::collect()?
+            .component
+            .into_values()
+            .flat_map(|c| c.executables)
+            .collect();
+
+        let mut plugins = BTreeMap::new();
+        for dir in std::iter::once(self.bin_path.clone()).chain(executable_search_dirs()) {
+            let Ok(entries) = std::fs::read_dir(&dir) else {
+                continue;
+            };
+            for entry in entries.filter_map(std::io::Result::ok) {
+                let name = entry.file_name().to_string_lossy().to_string();
+                if name.starts_with(PLUGIN_PREFIX)
+                    && !managed.contains(&name)
+                    && is_executable(&entry.path())
+                {
+                    plugins.entry(name).or_insert_with(|| entry.path());
+                }
+            }
+        }
+
+        Ok(plugins)
+    }
+
     fn can_remove(&self, component: &str) -> bool {
         // Published components are the ones downloadable, and hence removable.
         Components::contains_published(component)
@@ -321,29 +480,39 @@ impl Toolchain {
         Ok(download_cfg)
     }
 
-    pub fn install_if_nonexistent(&self, description: &DistToolchainDescription) -> Result<()> {
+    /// Installs the toolchain if it does not exist yet, from the revision of its channel published on
+    /// or before its date. Given a `lock`, the install fails unless the channel still provides the
+    /// locked binaries.
+    pub fn install_if_nonexistent(
+        &self,
+        description: &DistToolchainDescription,
+        lock: Option<&ToolchainLock>,
+    ) -> Result<()> {
         if !self.exists() {
             info!("toolchain '{}' does not exist; installing", description);
-            if let Ok((channel, hash)) = Channel::from_dist_channel(description) {
-                ensure_dir_exists(&self.bin_path)?;
-                let store = Store::from_env()?;
-                let config = Config::from_env()?;
-                if let Ok(true) = config.hash_matches(description, &hash) {
-                    info!("'{}' is already installed and up to date", self.name);
-                };
-                for cfg in channel.build_download_configs() {
-                    if store.has_component(&cfg.name, &cfg.version) {
-                        hard_or_symlink_file(
-                            &store
-                                .component_dir_path(&cfg.name, &cfg.version)
-                                .join(&cfg.name),
-                            &self.bin_path.join(&cfg.name),
-                        )?;
-                    } else {
-                        let downloaded = store.install_component(&cfg)?;
-                        for bin in downloaded {
-                            hard_or_symlink_file(&bin, &self.bin_path.join(&cfg.name))?;
-                        }
+            let revision = description.clone().resolve_revision();
+            let (channel, hash) = Channel::from_dist_channel(&revision)?;
+            if let Some(lock) = lock {
+                lock.verify_channel(&channel)?;
+            }
+            ensure_dir_exists(&self.bin_path)?;
+            let store = Store::from_env()?;
+            let config = Config::from_env()?;
+            if let Ok(true) = config.hash_matches(description, &hash) {
+                info!("'{}' is already installed and up to date", self.name);
+            };
+            for cfg in channel.build_download_configs(&self.selected_components()?) {
+                if store.has_component(&cfg.name, &cfg.version) {
+                    hard_or_symlink_file(
+                        &store
+                            .component_dir_path(&cfg.name, &cfg.version)
+                            .join(&cfg.name),
+                        &self.bin_path.join(&cfg.name),
+                    )?;
+                } else {
+                    let downloaded = store.install_component(&cfg)?;
+                    for bin in downloaded {
+                        hard_or_symlink_file(&bin, &self.bin_path.join(&cfg.name))?;
                     }
                 }
             }
@@ -403,7 +572,7 @@ mod tests {
 
     #[test]
     fn test_parse_name() -> Result<()> {
-        for name in [channel::LATEST, channel::NIGHTLY] {
+        for name in [channel::LATEST, channel::NIGHTLY, channel::STABLE] {
             let desc = DistToolchainDescription::from_str(name)?;
             assert_eq!(desc.name, DistToolchainName::from_str(name).unwrap());
             assert_eq!(desc.date, None);
@@ -466,7 +635,7 @@ mod tests {
             TARGET_X86_APPLE,
             TARGET_X86_LINUX,
         ] {
-            for name in [channel::LATEST, channel::NIGHTLY] {
+            for name in [channel::LATEST, channel::NIGHTLY, channel::STABLE] {
                 let toolchain = name.to_owned() + "-" + target;
                 let desc = DistToolchainDescription::from_str(&toolchain).unwrap();
 
@@ -504,4 +673,50 @@ mod tests {
         }
         Ok(())
     }
+
+    #[test]
+    fn revision_in_index() -> Result<()> {
+        let index =
+            RevisionIndex::from_toml(include_str!("../tests/revision-index-latest-example.toml"))?;
+        let revision = |channel: &str| -> Result<Option<String>> {
+            let description = DistToolchainDescription::from_str(channel)?;
+            Ok(description.revision_in(&index).map(|r| r.channel()))
+        };
+
+        assert_eq!(
+            revision("latest-2023-01-27")?.as_deref(),
+            Some("latest-2023-01-27")
+        );
+        assert_eq!(
+            revision("latest-2023-02-01")?.as_deref(),
+            Some("latest-2023-01-27")
+        );
+        assert_eq!(revision("latest-2023-01-01")?, None);
+        assert_eq!(revision("latest")?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn toolchain_selection() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let toolchain = Toolchain {
+            name: "latest".to_string(),
+            path: dir.path().to_path_buf(),
+            bin_path: dir.path().join("bin"),
+        };
+        assert!(toolchain.selection()?.is_none());
+
+        let selection = Profile {
+            components: vec!["forc".to_string(), "forc-wallet".to_string()],
+        };
+        toolchain.save_selection(Some(&selection))?;
+        assert_eq!(
+            toolchain.selection()?.unwrap().components,
+            selection.components
+        );
+
+        toolchain.save_selection(None)?;
+        assert!(toolchain.selection()?.is_none());
+        Ok(())
+    }
 }
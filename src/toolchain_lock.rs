@@ -0,0 +1,288 @@
+use anyhow::{bail, Result};
+use component::Components;
+use semver::Version;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use toml_edit::{de, ser};
+use tracing::warn;
+
+use crate::{
+    channel::{Channel, Package},
+    constants::{FUEL_TOOLCHAIN_LOCK_FILE, FUEL_TOOLCHAIN_TOML_FILE},
+    download::DownloadCfg,
+    file,
+    target_triple::TargetTriple,
+    toolchain::DistToolchainDescription,
+    toolchain_override::{ComponentSpec, ToolchainOverride},
+};
+
+const HEADER: &str =
+    "# This file is generated by `fuelup lock`. It is not intended for manual editing.\n";
+
+// Representation of a 'fuel-toolchain.lock', which records the exact binaries that the
+// 'fuel-toolchain.toml' next to it resolved to: the packages of its channel within [pkg], in the
+// same format as a channel, and its pinned components within [components].
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ToolchainLock {
+    pub toolchain: LockedToolchain,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub pkg: BTreeMap<String, Package>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub components: BTreeMap<String, Package>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct LockedToolchain {
+    pub channel: String,
+}
+
+impl ToolchainLock {
+    pub fn path(to: &ToolchainOverride) -> PathBuf {
+        to.path.with_file_name(FUEL_TOOLCHAIN_LOCK_FILE)
+    }
+
+    pub fn from_path(path: &Path) -> Result<Option<Self>> {
+        if !path.is_file() {
+            return Ok(None);
+        }
+        let f = file::read_file(FUEL_TOOLCHAIN_LOCK_FILE, path)?;
+        Ok(Some(Self::from_toml(&f)?))
+    }
+
+    pub(crate) fn from_toml(toml: &str) -> Result<Self> {
+        let lock: ToolchainLock = de::from_str(toml)?;
+        Ok(lock)
+    }
+
+    pub fn to_string_pretty(&self) -> Result<String> {
+        Ok(format!("{HEADER}{}", ser::to_string_pretty(self)?))
+    }
+
+    pub fn write(&self, path: &Path) -> Result<()> {
+        file::write_file(path, &self.to_string_pretty()?)?;
+        Ok(())
+    }
+
+    // Reads the lock of a 'fuel-toolchain.toml', if there is one. A lock that no longer matches
+    // the 'fuel-toolchain.toml', eg. after changing its channel, is ignored.
+    pub fn for_override(to: &ToolchainOverride) -> Result<Option<Self>> {
+        // A custom toolchain has no channel to lock, so any lock beside it is left alone.
+        if to.cfg.toolchain.channel.is_none() {
+            return Ok(None);
+        }
+        match Self::from_path(&Self::path(to))? {
+            Some(lock) if lock.is_up_to_date(to) => Ok(Some(lock)),
+            Some(_) => {
+                warn!(
+                    "warning: {} is out of date with {}; run 'fuelup lock' to update it",
+                    FUEL_TOOLCHAIN_LOCK_FILE, FUEL_TOOLCHAIN_TOML_FILE
+                );
+                Ok(None)
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn is_up_to_date(&self, to: &ToolchainOverride) -> bool {
+        let specs: Vec<_> = versioned_specs(to).collect();
+
+        to.cfg.toolchain.channel.as_ref().map(|c| c.to_string())
+            == Some(self.toolchain.channel.clone())
+            && self.components.len() == specs.len()
+            && specs.iter().all(|&(name, spec)| {
+                self.components
+                    .get(name)
+                    .is_some_and(|locked| spec.matches(&locked.version))
+            })
+    }
+
+    // Resolves a 'fuel-toolchain.toml' into a lock. Entries of a `previous` lock that still match
+    // are kept as they are, so that only what changed is resolved again.
+    pub fn resolve(to: &ToolchainOverride, previous: Option<&Self>) -> Result<Self> {
+        let Some(channel) = to.cfg.toolchain.channel.as_ref().map(|c| c.to_string()) else {
+            bail!(
+                "'{}' declares the custom toolchain '{}', which has no channel to lock",
+                to.path.display(),
+                to.cfg.toolchain
+            );
+        };
+
+        let pkg = match previous.filter(|p| p.toolchain.channel == channel && !p.pkg.is_empty()) {
+            Some(previous) => previous.pkg.clone(),
+            None => {
+                // Locked as installed, ie. from the nearest earlier revision if the channel was not
+                // published on its date.
+                let description = DistToolchainDescription::from_str(&channel)?.resolve_revision();
+                let (dist_channel, _) = Channel::from_dist_channel(&description)?;
+                dist_channel
+                    .pkg
+                    .into_iter()
+                    .filter(|(name, _)| Components::contains_published(name))
+                    .collect()
+            }
+        };
+
+        let mut components = BTreeMap::new();
+        for (name, spec) in versioned_specs(to) {
+            let target = TargetTriple::from_component(name)?;
+            let locked = previous
+                .and_then(|p| p.components.get(name))
+                .filter(|locked| spec.matches(&locked.version));
+
+            let package = match locked {
+                Some(locked) if locked.target.contains_key(&target.to_string()) => locked.clone(),
+                // Locked on another platform; record the binary for this one as well.
+                Some(locked) => {
+                    let mut package = locked.clone();
+                    let host = DownloadCfg::new(name, target, Some(locked.version.clone()))?;
+                    package.target.extend(host.to_package()?.target);
+                    package
+                }
+                None => {
+                    let version = spec.resolve_published(name)?;
+                    DownloadCfg::new(name, target, Some(version))?.to_package()?
+                }
+            };
+            components.insert(name.clone(), package);
+        }
+
+        Ok(Self {
+            toolchain: LockedToolchain { channel },
+            pkg,
+            components,
+        })
+    }
+
+    pub fn locked_version(&self, component: &str) -> Option<&Version> {
+        self.components.get(component).map(|p| &p.version)
+    }
+
+    // Checks that a freshly downloaded channel still provides the binaries locked for this host.
+    pub fn verify_channel(&self, channel: &Channel) -> Result<()> {
+        for (name, locked) in &self.pkg {
+            let target = TargetTriple::from_component(name)?.to_string();
+            let Some(locked_binary) = locked.target.get(&target) else {
+                continue;
+            };
+
+            let published = channel
+                .pkg
+                .get(name)
+                .filter(|p| p.version == locked.version)
+                .and_then(|p| p.target.get(&target));
+            if published != Some(locked_binary) {
+                bail!(
+                    "{} {} in channel '{}' does not match {}; the channel may have been republished.
+Run 'fuelup lock --update' to accept the published binaries.",
+                    name,
+                    locked.version,
+                    self.toolchain.channel,
+                    FUEL_TOOLCHAIN_LOCK_FILE
+                );
+            }
+        }
+        Ok(())
+    }
+
+    // The download of a pinned component, verified against the hash within `lock` if it is locked
+    // for this host.
+    pub fn pinned_download_cfg(
+        lock: Option<&Self>,
+        component: &str,
+        version: &Version,
+    ) -> Result<DownloadCfg> {
+        let locked = match lock {
+            Some(lock) => lock.download_cfg(component, version)?,
+            None => None,
+        };
+        match locked {
+            Some(cfg) => Ok(cfg),
+            None => DownloadCfg::new(
+                component,
+                TargetTriple::from_component(component)?,
+                Some(version.clone()),
+            ),
+        }
+    }
+
+    // The download of a pinned component, verified against its locked hash, if it is locked for
+    // this host.
+    pub fn download_cfg(&self, component: &str, version: &Version) -> Result<Option<DownloadCfg>> {
+        let target = TargetTriple::from_component(component)?.to_string();
+        match self.components.get(component) {
+            Some(locked) if &locked.version == version && locked.target.contains_key(&target) => {
+                Ok(Some(DownloadCfg::from_package(component, locked.clone())?))
+            }
+            _ => Ok(None),
+        }
+    }
+}
+
+// Components built from a path or git revision have no published binaries to lock.
+fn versioned_specs(to: &ToolchainOverride) -> impl Iterator<Item = (&String, &ComponentSpec)> {
+    to.cfg
+        .components
+        .iter()
+        .flatten()
+        .filter(|(_, spec)| spec.is_versioned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::file::read_file;
+
+    const LOCK: &str = r#"# This file is generated by `fuelup lock`. It is not intended for manual editing.
+[toolchain]
+channel = "latest-2022-08-31"
+
+[pkg.forc]
+version = "0.17.0"
+
+[pkg.forc.target.linux_amd64]
+url = "https://github.com/FuelLabs/sway/releases/download/v0.17.0/forc-binaries-linux_amd64.tar.gz"
+hash = "abcdef"
+"#;
+
+    fn example_channel() -> Channel {
+        let channel_path = std::env::current_dir()
+            .unwrap()
+            .join("tests/channel-fuel-latest-example.toml");
+        Channel::from_toml(&read_file("channel-fuel-latest-example", &channel_path).unwrap())
+            .unwrap()
+    }
+
+    #[test]
+    fn lock_roundtrip() {
+        let lock = ToolchainLock::from_toml(LOCK).unwrap();
+        assert_eq!(lock.toolchain.channel, "latest-2022-08-31");
+        assert_eq!(lock.pkg["forc"].version, Version::new(0, 17, 0));
+        assert!(lock.components.is_empty());
+        assert_eq!(lock.to_string_pretty().unwrap(), LOCK);
+    }
+
+    #[test]
+    fn verify_channel() {
+        let channel = example_channel();
+        let mut lock = ToolchainLock {
+            toolchain: LockedToolchain {
+                channel: "latest-2022-08-31".to_string(),
+            },
+            pkg: channel.pkg.clone(),
+            components: BTreeMap::new(),
+        };
+        assert!(lock.verify_channel(&channel).is_ok());
+
+        let target = TargetTriple::from_component("forc").unwrap().to_string();
+        lock.pkg
+            .get_mut("forc")
+            .unwrap()
+            .target
+            .get_mut(&target)
+            .unwrap()
+            .hash = "republished".to_string();
+        assert!(lock.verify_channel(&channel).is_err());
+    }
+}
@@ -1,20 +1,29 @@
 use anyhow::{bail, Result};
-use semver::Version;
+use component::Components;
+use semver::{Version, VersionReq};
 use serde::de::Error;
 use serde::ser::SerializeStruct;
 use serde::{Deserialize, Deserializer, Serialize};
 use std::fmt;
 use std::str::FromStr;
-use std::{collections::HashMap, path::PathBuf};
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
 use time::Date;
-use toml_edit::{de, ser, value, Document};
+use toml_edit::{de, ser, value, Document, InlineTable, Item, Table};
 use tracing::{info, warn};
 
-use crate::channel::{is_beta_toolchain, LATEST, NIGHTLY};
+use crate::channel::{LATEST, NIGHTLY, STABLE};
+use crate::channel_index::{ChannelIndex, DATED_CHANNELS};
 use crate::constants::{DATE_FORMAT, FUEL_TOOLCHAIN_TOML_FILE};
+use crate::store::Store;
 use crate::toolchain::{DistToolchainDescription, Toolchain};
 use crate::{
-    download::DownloadCfg, file, path::get_fuel_toolchain_toml, target_triple::TargetTriple,
+    download::{get_published_versions, DownloadCfg},
+    file,
+    path::{find_fuel_toolchain_toml, get_fuel_toolchain_toml},
+    target_triple::TargetTriple,
 };
 
 // For composability with other functionality of fuelup, we want to add
@@ -31,14 +40,227 @@ pub struct ToolchainOverride {
 #[derive(Debug, Deserialize, Serialize)]
 pub struct OverrideCfg {
     pub toolchain: ToolchainCfg,
-    pub components: Option<HashMap<String, Version>>,
+    pub components: Option<HashMap<String, ComponentSpec>>,
+}
+
+// Represents a component's entry within the [components] table in 'fuel-toolchain.toml': either an
+// exact version, eg. "0.33.0", a version requirement, eg. "^0.35" or ">=0.17, <0.18", or a table
+// declaring where to get an unreleased build from, eg. { path = "../sway/target/release" } or
+// { git = "https://github.com/FuelLabs/fuel-core", rev = "abc123" }.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ComponentSpec {
+    Version(Version),
+    VersionReq(VersionReq),
+    // A directory of binaries, relative to the 'fuel-toolchain.toml' it is declared in.
+    Path(PathBuf),
+    // A git revision, checked out and built locally.
+    Git { url: String, rev: String },
+}
+
+// The forms a [components] entry may take within the toml.
+#[derive(Deserialize, Serialize)]
+#[serde(untagged)]
+enum ComponentSpecToml {
+    Version(String),
+    Path(PathSource),
+    Git(GitSource),
+}
+
+#[derive(Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+struct PathSource {
+    path: PathBuf,
+}
+
+#[derive(Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+struct GitSource {
+    git: String,
+    rev: String,
+}
+
+impl fmt::Display for ComponentSpec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ComponentSpec::Version(version) => write!(f, "{version}"),
+            ComponentSpec::VersionReq(req) => write!(f, "{req}"),
+            ComponentSpec::Path(path) => write!(f, "{}", path.display()),
+            ComponentSpec::Git { url, rev } => write!(f, "{url}#{rev}"),
+        }
+    }
+}
+
+impl FromStr for ComponentSpec {
+    type Err = anyhow::Error;
+    fn from_str(s: &str) -> Result<Self> {
+        // Unlike cargo, a bare version is exact rather than a caret requirement.
+        if let Ok(version) = Version::parse(s) {
+            return Ok(ComponentSpec::Version(version));
+        }
+        match VersionReq::parse(s) {
+            Ok(req) => Ok(ComponentSpec::VersionReq(req)),
+            Err(e) => bail!("Invalid version or version requirement '{}': {}", s, e),
+        }
+    }
+}
+
+impl Serialize for ComponentSpec {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.to_spec_toml().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for ComponentSpec {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const EXPECTED: &str = "a version, eg. \"0.33.0\", a version requirement, eg. \"^0.35\", or a table with either 'path', or 'git' and 'rev'";
+
+        match ComponentSpecToml::deserialize(deserializer).map_err(|_| Error::custom(EXPECTED))? {
+            ComponentSpecToml::Version(spec_str) => spec_str.parse().map_err(|_| {
+                Error::invalid_value(serde::de::Unexpected::Str(&spec_str), &EXPECTED)
+            }),
+            ComponentSpecToml::Path(PathSource { path }) => Ok(ComponentSpec::Path(path)),
+            ComponentSpecToml::Git(GitSource { git, rev }) => {
+                Ok(ComponentSpec::Git { url: git, rev })
+            }
+        }
+    }
 }
 
-// Represents the [toolchain] table in 'fuel-toolchain.toml'.
+impl ComponentSpec {
+    fn to_spec_toml(&self) -> ComponentSpecToml {
+        match self {
+            ComponentSpec::Version(_) | ComponentSpec::VersionReq(_) => {
+                ComponentSpecToml::Version(self.to_string())
+            }
+            ComponentSpec::Path(path) => ComponentSpecToml::Path(PathSource { path: path.clone() }),
+            ComponentSpec::Git { url, rev } => ComponentSpecToml::Git(GitSource {
+                git: url.clone(),
+                rev: rev.clone(),
+            }),
+        }
+    }
+
+    // The value of this spec within the [components] table.
+    fn to_toml_item(&self) -> Item {
+        match self {
+            ComponentSpec::Path(path) => {
+                let mut table = InlineTable::new();
+                table.insert("path", path.display().to_string().into());
+                value(table)
+            }
+            ComponentSpec::Git { url, rev } => {
+                let mut table = InlineTable::new();
+                table.insert("git", url.as_str().into());
+                table.insert("rev", rev.as_str().into());
+                value(table)
+            }
+            _ => value(self.to_string()),
+        }
+    }
+
+    // Parses a component along with a version or version requirement, eg. 'forc@0.35.0'.
+    pub fn from_versioned_component(s: &str) -> Result<(String, Self)> {
+        let Some((component, spec)) = s.split_once('@') else {
+            bail!(
+                "Expected <component>@<version>, eg. forc@0.35.0, but got '{}'",
+                s
+            );
+        };
+        if !Components::collect()?.component.contains_key(component) {
+            bail!("Unknown component '{}'", component);
+        }
+        Ok((component.to_string(), Self::from_str(spec)?))
+    }
+
+    // Whether this spec is resolved to a released version, as opposed to a local or git build.
+    pub fn is_versioned(&self) -> bool {
+        matches!(
+            self,
+            ComponentSpec::Version(_) | ComponentSpec::VersionReq(_)
+        )
+    }
+
+    pub fn matches(&self, version: &Version) -> bool {
+        match self {
+            ComponentSpec::Version(v) => v == version,
+            ComponentSpec::VersionReq(req) => req.matches(version),
+            ComponentSpec::Path(_) | ComponentSpec::Git { .. } => false,
+        }
+    }
+
+    // Resolves the concrete version of `component` to run. Requirements resolve to the highest
+    // matching version within the store, so that proxies don't query releases on every call, and
+    // only to the highest matching published version if none is installed.
+    pub fn resolve(&self, component: &str) -> Result<Version> {
+        if let ComponentSpec::VersionReq(req) = self {
+            let installed = Store::from_env()?.installed_versions(component)?;
+            if let Some(version) = installed.into_iter().filter(|v| req.matches(v)).max() {
+                return Ok(version);
+            }
+        }
+        self.resolve_published(component)
+    }
+
+    // Resolves the concrete version of `component` to install. Requirements resolve to the highest
+    // matching published version, or when releases can't be fetched, eg. offline, to the highest
+    // matching version within the store.
+    pub fn resolve_latest(&self, component: &str) -> Result<Version> {
+        match (self, self.resolve_published(component)) {
+            (ComponentSpec::VersionReq(req), Err(e)) => {
+                let installed = Store::from_env()?.installed_versions(component)?;
+                match installed.into_iter().filter(|v| req.matches(v)).max() {
+                    Some(version) => {
+                        warn!(
+                            "warning: could not resolve '{}' for {} against published releases, using {} from the store: {}",
+                            req, component, version, e
+                        );
+                        Ok(version)
+                    }
+                    None => Err(e),
+                }
+            }
+            (_, published) => published,
+        }
+    }
+
+    // Resolves the highest published version of `component` matching this spec.
+    pub fn resolve_published(&self, component: &str) -> Result<Version> {
+        let req = match self {
+            ComponentSpec::Version(version) => return Ok(version.clone()),
+            ComponentSpec::VersionReq(req) => req,
+            ComponentSpec::Path(_) | ComponentSpec::Git { .. } => {
+                bail!(
+                    "'{}' is built from {} rather than released",
+                    component,
+                    self
+                )
+            }
+        };
+
+        match get_published_versions(component)?
+            .into_iter()
+            .filter(|v| req.matches(v))
+            .max()
+        {
+            Some(version) => Ok(version),
+            None => bail!("No published version of '{}' matches '{}'", component, req),
+        }
+    }
+}
+
+// Represents the [toolchain] table in 'fuel-toolchain.toml', which declares either the channel of
+// a distributable toolchain or the name of a custom toolchain.
 #[derive(Debug, Deserialize)]
 pub struct ToolchainCfg {
-    #[serde(deserialize_with = "deserialize_channel")]
-    pub channel: Channel,
+    #[serde(default, deserialize_with = "deserialize_channel")]
+    pub channel: Option<Channel>,
+    pub name: Option<String>,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -52,14 +274,59 @@ impl Serialize for ToolchainCfg {
     where
         S: serde::Serializer,
     {
-        let mut c = serializer.serialize_struct("ToolchainCfg", 2)?;
-        c.serialize_field("channel", &self.channel.to_string())?;
+        let mut c = serializer.serialize_struct("ToolchainCfg", 1)?;
+        if let Some(channel) = &self.channel {
+            c.serialize_field("channel", &channel.to_string())?;
+        }
+        if let Some(name) = &self.name {
+            c.serialize_field("name", name)?;
+        }
 
         c.end()
     }
 }
 
-pub fn deserialize_channel<'de, D>(deserializer: D) -> Result<Channel, D::Error>
+impl fmt::Display for ToolchainCfg {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.channel, &self.name) {
+            (Some(channel), _) => write!(f, "{channel}"),
+            (None, Some(name)) => write!(f, "{name}"),
+            (None, None) => Ok(()),
+        }
+    }
+}
+
+impl ToolchainCfg {
+    // The description of the declared distributable toolchain, if a channel is declared.
+    pub fn description(&self) -> Option<DistToolchainDescription> {
+        // from_toml() ensures that a declared channel is a valid description.
+        self.channel
+            .as_ref()
+            .and_then(|channel| DistToolchainDescription::from_str(&channel.to_string()).ok())
+    }
+
+    // The name of the declared toolchain as installed, ie. including the target triple of a
+    // distributable toolchain.
+    pub fn toolchain_name(&self) -> String {
+        match self.description() {
+            Some(description) => description.to_string(),
+            None => self.to_string(),
+        }
+    }
+}
+
+// Eg. "one of <latest-YYYY-MM-DD|nightly-YYYY-MM-DD|stable-YYYY-MM-DD|beta-1|beta-2|beta-3>".
+fn expected_channel() -> String {
+    let index = ChannelIndex::load();
+    let channels: Vec<String> = DATED_CHANNELS
+        .iter()
+        .map(|name| format!("{name}-YYYY-MM-DD"))
+        .chain(index.names().map(str::to_string))
+        .collect();
+    format!("one of <{}>", channels.join("|"))
+}
+
+pub fn deserialize_channel<'de, D>(deserializer: D) -> Result<Option<Channel>, D::Error>
 where
     D: Deserializer<'de>,
 {
@@ -69,10 +336,10 @@ where
         |_| {
             Err(Error::invalid_value(
                 serde::de::Unexpected::Str(&channel_str),
-                &"one of <latest-YYYY-MM-DD|nightly-YYYY-MM-DD|beta-1|beta-2|beta-3>",
+                &expected_channel().as_str(),
             ))
         },
-        Result::Ok,
+        |channel| Ok(Some(channel)),
     )
 }
 
@@ -88,7 +355,7 @@ impl fmt::Display for Channel {
 impl FromStr for Channel {
     type Err = anyhow::Error;
     fn from_str(s: &str) -> Result<Self> {
-        if is_beta_toolchain(s) {
+        if ChannelIndex::load().contains(s) {
             return Ok(Self {
                 name: s.to_string(),
                 date: None,
@@ -101,7 +368,7 @@ impl FromStr for Channel {
                 date: Date::parse(d, DATE_FORMAT).ok(),
             })
         } else {
-            if s == LATEST || s == NIGHTLY {
+            if s == LATEST || s == NIGHTLY || s == STABLE {
                 bail!("'{s}' without date specifier is forbidden");
             }
             bail!("Invalid str for channel: '{}'", s);
@@ -114,6 +381,16 @@ impl ToolchainOverride {
     // This representation is an OverrideCfg and the file path.
     pub(crate) fn from_path(path: PathBuf) -> Result<Self> {
         let f = file::read_file(FUEL_TOOLCHAIN_TOML_FILE, path.as_path())?;
+        // The declared channel may have been published after this release of fuelup.
+        let document = f.parse::<Document>().ok();
+        if let Some(channel) = document
+            .as_ref()
+            .and_then(|d| d.get("toolchain"))
+            .and_then(|t| t.get("channel"))
+            .and_then(Item::as_str)
+        {
+            ChannelIndex::refresh_if_unknown(channel);
+        }
         let cfg: OverrideCfg = OverrideCfg::from_toml(&f)?;
         Ok(Self { cfg, path })
     }
@@ -121,17 +398,31 @@ impl ToolchainOverride {
     pub fn to_toml(&self) -> Document {
         let mut document = toml_edit::Document::new();
 
-        document["toolchain"]["channel"] = value(self.cfg.toolchain.channel.to_string());
+        if let Some(channel) = &self.cfg.toolchain.channel {
+            document["toolchain"]["channel"] = value(channel.to_string());
+        }
+        if let Some(name) = &self.cfg.toolchain.name {
+            document["toolchain"]["name"] = value(name);
+        }
         if let Some(components) = &self.cfg.components {
             for (k, v) in components.iter() {
-                document["components"][k] = value(v.to_string());
+                document["components"][k] = v.to_toml_item();
             }
         }
         document
     }
 
     pub fn from_project_root() -> Option<ToolchainOverride> {
-        if let Some(fuel_toolchain_toml_file) = get_fuel_toolchain_toml() {
+        Self::from_found(get_fuel_toolchain_toml())
+    }
+
+    // Like from_project_root(), but searches from the given project directory instead.
+    pub fn from_project_dir(dir: &Path) -> Option<ToolchainOverride> {
+        Self::from_found(find_fuel_toolchain_toml(dir))
+    }
+
+    fn from_found(fuel_toolchain_toml_file: Option<PathBuf>) -> Option<ToolchainOverride> {
+        if let Some(fuel_toolchain_toml_file) = fuel_toolchain_toml_file {
             match ToolchainOverride::from_path(fuel_toolchain_toml_file) {
                 Ok(to) => Some(to),
                 Err(e) => {
@@ -144,7 +435,7 @@ impl ToolchainOverride {
         }
     }
 
-    pub fn get_component_version(&self, component: &str) -> Option<&Version> {
+    pub fn get_component_spec(&self, component: &str) -> Option<&ComponentSpec> {
         if let Some(components) = &self.cfg.components {
             components.get(component)
         } else {
@@ -156,7 +447,7 @@ impl ToolchainOverride {
         match &self.cfg.components {
             None => warn!(
                 "warning: overriding toolchain '{}' in {} does not have any components listed",
-                &self.cfg.toolchain.channel, FUEL_TOOLCHAIN_TOML_FILE
+                &self.cfg.toolchain, FUEL_TOOLCHAIN_TOML_FILE
             ),
             Some(components) => {
                 for component in components.keys() {
@@ -178,8 +469,142 @@ impl ToolchainOverride {
     }
 }
 
+// A 'fuel-toolchain.toml' edited in place, preserving the comments and formatting of the rest of
+// the file. The file is created if it does not exist yet.
+pub struct OverrideDocument {
+    pub path: PathBuf,
+    document: Document,
+}
+
+impl OverrideDocument {
+    // Starts a new 'fuel-toolchain.toml' at `path`, replacing any existing file once saved.
+    pub fn new(path: PathBuf) -> Self {
+        Self {
+            path,
+            document: Document::new(),
+        }
+    }
+
+    pub fn open(path: PathBuf) -> Result<Self> {
+        if !path.is_file() {
+            return Ok(Self::new(path));
+        }
+        let document = file::read_file(FUEL_TOOLCHAIN_TOML_FILE, &path)?.parse::<Document>()?;
+        Ok(Self { path, document })
+    }
+
+    // Opens the 'fuel-toolchain.toml' overriding the toolchain of `project_dir`, or of the current
+    // project by default, or a new one within that directory if there is none yet.
+    pub fn find(project_dir: Option<&Path>) -> Result<Self> {
+        let path = match project_dir {
+            Some(dir) => {
+                find_fuel_toolchain_toml(dir).unwrap_or_else(|| dir.join(FUEL_TOOLCHAIN_TOML_FILE))
+            }
+            None => match get_fuel_toolchain_toml() {
+                Some(path) => path,
+                None => std::env::current_dir()?.join(FUEL_TOOLCHAIN_TOML_FILE),
+            },
+        };
+        Self::open(path)
+    }
+
+    // The declared channel, as written.
+    pub fn channel(&self) -> Option<&str> {
+        self.document
+            .get("toolchain")
+            .and_then(|t| t.get("channel"))
+            .and_then(|c| c.as_str())
+    }
+
+    pub fn set_channel(&mut self, channel: &str) -> Result<()> {
+        if DistToolchainDescription::from_str(channel).is_err() {
+            bail!(
+                "Invalid channel '{}', expected {}",
+                channel,
+                expected_channel()
+            );
+        }
+
+        let toolchain = self.table_mut("toolchain")?;
+        set_value(toolchain, "channel", value(channel));
+        // A channel replaces a custom toolchain, since only one of them may be declared.
+        toolchain.remove("name");
+        Ok(())
+    }
+
+    pub fn pin(&mut self, component: &str, spec: &ComponentSpec) -> Result<()> {
+        set_value(
+            self.table_mut("components")?,
+            component,
+            spec.to_toml_item(),
+        );
+        Ok(())
+    }
+
+    // Removes the entry of `component`, along with the [components] table once it is empty.
+    // Returns whether the component was pinned.
+    pub fn unpin(&mut self, component: &str) -> bool {
+        let Some(components) = self
+            .document
+            .get_mut("components")
+            .and_then(Item::as_table_mut)
+        else {
+            return false;
+        };
+        let removed = components.remove(component).is_some();
+        if components.is_empty() {
+            self.document.as_table_mut().remove("components");
+        }
+        removed
+    }
+
+    // Writes the document back, after making sure that it is still a valid 'fuel-toolchain.toml'.
+    pub fn save(&self) -> Result<()> {
+        let toml = self.document.to_string();
+        if let Err(e) = OverrideCfg::from_toml(&toml) {
+            bail!(
+                "Refusing to write an invalid {}: {}",
+                FUEL_TOOLCHAIN_TOML_FILE,
+                e
+            );
+        }
+        file::write_file(&self.path, &toml)?;
+        Ok(())
+    }
+
+    fn table_mut(&mut self, key: &str) -> Result<&mut Table> {
+        let is_empty = self.document.as_table().is_empty();
+        let item = self.document.entry(key).or_insert_with(|| {
+            let mut table = Table::new();
+            if is_empty {
+                table.decor_mut().set_prefix("");
+            }
+            Item::Table(table)
+        });
+        match item.as_table_mut() {
+            Some(table) => {
+                // An implicit table is only printed through its subtables, eg. '[components.forc]'.
+                table.set_implicit(false);
+                Ok(table)
+            }
+            None => bail!("'{}' in {} is not a table", key, FUEL_TOOLCHAIN_TOML_FILE),
+        }
+    }
+}
+
+// Sets `key` within `table`, keeping the comments around any value it replaces.
+fn set_value(table: &mut Table, key: &str, mut item: Item) {
+    if let (Some(old), Some(new)) = (table.get(key).and_then(Item::as_value), item.as_value_mut()) {
+        *new.decor_mut() = old.decor().clone();
+    }
+    table[key] = item;
+}
+
 impl OverrideCfg {
-    pub fn new(toolchain: ToolchainCfg, components: Option<HashMap<String, Version>>) -> Self {
+    pub fn new(
+        toolchain: ToolchainCfg,
+        components: Option<HashMap<String, ComponentSpec>>,
+    ) -> Self {
         Self {
             toolchain,
             components,
@@ -191,8 +616,26 @@ impl OverrideCfg {
     // an OverrideCfg with its file path.
     pub(crate) fn from_toml(toml: &str) -> Result<Self> {
         let cfg: OverrideCfg = de::from_str(toml)?;
-        if DistToolchainDescription::from_str(&cfg.toolchain.channel.to_string()).is_err() {
-            bail!("Invalid channel '{}'", &cfg.toolchain.channel)
+        match (&cfg.toolchain.channel, &cfg.toolchain.name) {
+            (Some(channel), None) => {
+                if DistToolchainDescription::from_str(&channel.to_string()).is_err() {
+                    bail!("Invalid channel '{}'", channel)
+                }
+            }
+            (None, Some(name)) => {
+                if ChannelIndex::load().split_channel(name).is_some() {
+                    bail!(
+                        "Cannot use distributable toolchain name '{}' as a custom toolchain name; declare it as 'channel' instead",
+                        name
+                    )
+                }
+            }
+            (Some(_), Some(_)) => {
+                bail!("'[toolchain]' declares both 'channel' and 'name'; only one of them may be declared")
+            }
+            (None, None) => {
+                bail!("'[toolchain]' must declare either a 'channel' or the 'name' of a custom toolchain")
+            }
         }
 
         if let Some(components) = cfg.components.as_ref() {
@@ -211,7 +654,7 @@ impl OverrideCfg {
 
 #[cfg(test)]
 mod tests {
-    use crate::channel::{BETA_1, BETA_2, BETA_3, NIGHTLY};
+    use crate::channel::NIGHTLY;
 
     use super::*;
 
@@ -222,7 +665,7 @@ channel = "latest-2023-01-09"
 "#;
         let cfg = OverrideCfg::from_toml(TOML).unwrap();
 
-        assert_eq!(cfg.toolchain.channel.to_string(), "latest-2023-01-09");
+        assert_eq!(cfg.toolchain.to_string(), "latest-2023-01-09");
 
         assert!(cfg.components.is_none());
         assert_eq!(TOML, cfg.to_string_pretty().unwrap());
@@ -238,12 +681,97 @@ forc = "0.33.0"
 "#;
         let cfg = OverrideCfg::from_toml(TOML).unwrap();
 
-        assert_eq!(cfg.toolchain.channel.to_string(), "nightly-2023-01-09");
+        assert_eq!(cfg.toolchain.to_string(), "nightly-2023-01-09");
         assert_eq!(
             cfg.components.as_ref().unwrap().get("forc").unwrap(),
-            &Version::new(0, 33, 0)
+            &ComponentSpec::Version(Version::new(0, 33, 0))
+        );
+        assert_eq!(TOML, cfg.to_string_pretty().unwrap());
+    }
+
+    #[test]
+    fn parse_toolchain_override_version_requirements() {
+        const TOML: &str = r#"[toolchain]
+channel = "latest-2023-01-09"
+
+[components]
+forc = "^0.35"
+"#;
+        let cfg = OverrideCfg::from_toml(TOML).unwrap();
+
+        let ComponentSpec::VersionReq(req) = cfg.components.as_ref().unwrap()["forc"].clone()
+        else {
+            panic!("expected a version requirement");
+        };
+        assert!(req.matches(&Version::new(0, 35, 3)));
+        assert!(!req.matches(&Version::new(0, 36, 0)));
+        assert_eq!(TOML, cfg.to_string_pretty().unwrap());
+
+        let spec = ComponentSpec::from_str(">=0.17, <0.18").unwrap();
+        assert_eq!(spec.to_string(), ">=0.17, <0.18");
+        assert!(ComponentSpec::from_str("not-a-version").is_err());
+    }
+
+    #[test]
+    fn parse_toolchain_override_sources() {
+        const TOML: &str = r#"[toolchain]
+channel = "latest-2023-01-09"
+
+[components]
+forc = { path = "../sway/target/release" }
+fuel-core = { git = "https://github.com/FuelLabs/fuel-core", rev = "abc123" }
+"#;
+        let cfg = OverrideCfg::from_toml(TOML).unwrap();
+        let components = cfg.components.clone().unwrap();
+
+        assert_eq!(
+            components["forc"],
+            ComponentSpec::Path(PathBuf::from("../sway/target/release"))
         );
+        assert_eq!(
+            components["fuel-core"],
+            ComponentSpec::Git {
+                url: "https://github.com/FuelLabs/fuel-core".to_string(),
+                rev: "abc123".to_string()
+            }
+        );
+        let reparsed = OverrideCfg::from_toml(&cfg.to_string_pretty().unwrap()).unwrap();
+        assert_eq!(reparsed.components.unwrap(), components);
+
+        const MIXED_SOURCES: &str = r#"[toolchain]
+channel = "latest-2023-01-09"
+
+[components]
+forc = { path = "../sway/target/release", rev = "abc123" }
+"#;
+        assert!(OverrideCfg::from_toml(MIXED_SOURCES).is_err());
+    }
+
+    #[test]
+    fn parse_toolchain_override_custom_toolchain() {
+        const TOML: &str = r#"[toolchain]
+name = "fuel-testnet"
+
+[components]
+forc = "0.33.0"
+"#;
+        let cfg = OverrideCfg::from_toml(TOML).unwrap();
+
+        assert!(cfg.toolchain.channel.is_none());
+        assert!(cfg.toolchain.description().is_none());
+        assert_eq!(cfg.toolchain.toolchain_name(), "fuel-testnet");
         assert_eq!(TOML, cfg.to_string_pretty().unwrap());
+
+        const DISTRIBUTABLE_NAME: &str = r#"[toolchain]
+name = "latest"
+"#;
+        const CHANNEL_AND_NAME: &str = r#"[toolchain]
+channel = "beta-3"
+name = "fuel-testnet"
+"#;
+        for toml in [DISTRIBUTABLE_NAME, CHANNEL_AND_NAME] {
+            assert!(OverrideCfg::from_toml(toml).is_err());
+        }
     }
 
     #[test]
@@ -260,7 +788,7 @@ channel = "nightly"
         let e = result.unwrap_err();
         assert_eq!(e
             .to_string(),
-            "invalid value: string \"latest\", expected one of <latest-YYYY-MM-DD|nightly-YYYY-MM-DD|beta-1|beta-2|beta-3> for key `toolchain.channel`".to_string());
+            "invalid value: string \"latest\", expected one of <latest-YYYY-MM-DD|nightly-YYYY-MM-DD|stable-YYYY-MM-DD|beta-1|beta-2|beta-3> for key `toolchain.channel`".to_string());
 
         let result = OverrideCfg::from_toml(NIGHTLY);
         assert!(result.is_err());
@@ -268,7 +796,7 @@ channel = "nightly"
 
         assert_eq!(e
             .to_string(),
-            "invalid value: string \"nightly\", expected one of <latest-YYYY-MM-DD|nightly-YYYY-MM-DD|beta-1|beta-2|beta-3> for key `toolchain.channel`".to_string());
+            "invalid value: string \"nightly\", expected one of <latest-YYYY-MM-DD|nightly-YYYY-MM-DD|stable-YYYY-MM-DD|beta-1|beta-2|beta-3> for key `toolchain.channel`".to_string());
     }
 
     #[test]
@@ -297,10 +825,12 @@ channel = "beta-2"
 
     #[test]
     fn channel_from_str() {
-        assert!(Channel::from_str(BETA_1).is_ok());
-        assert!(Channel::from_str(BETA_2).is_ok());
-        assert!(Channel::from_str(BETA_3).is_ok());
+        assert!(Channel::from_str("beta-1").is_ok());
+        assert!(Channel::from_str("beta-2").is_ok());
+        assert!(Channel::from_str("beta-3").is_ok());
         assert!(Channel::from_str(NIGHTLY).is_err());
         assert!(Channel::from_str(LATEST).is_err());
+        assert!(Channel::from_str(STABLE).is_err());
+        assert!(Channel::from_str("stable-2023-02-01").is_ok());
     }
 }
@@ -0,0 +1,48 @@
+use anyhow::Result;
+
+pub mod testcfg;
+use testcfg::FuelupState;
+
+#[test]
+fn fuelup_bisect_good_after_bad() -> Result<()> {
+    testcfg::setup(FuelupState::Empty, &|cfg| {
+        let output = cfg.fuelup(&[
+            "bisect",
+            "--good",
+            "2023-02-01",
+            "--bad",
+            "2023-01-01",
+            "forc",
+            "test",
+        ]);
+        assert_eq!(
+            output.stdout,
+            "--good (2023-02-01) must be earlier than --bad (2023-01-01)\n"
+        );
+    })?;
+
+    Ok(())
+}
+
+#[test]
+fn fuelup_bisect_undated_channel() -> Result<()> {
+    testcfg::setup(FuelupState::Empty, &|cfg| {
+        let output = cfg.fuelup(&[
+            "bisect",
+            "--channel",
+            "beta-3",
+            "--good",
+            "2023-01-01",
+            "--bad",
+            "2023-02-01",
+            "forc",
+            "test",
+        ]);
+        assert_eq!(
+            output.stdout,
+            "'beta-3' is not published by date and has no revisions; expected one of latest, nightly, stable\n"
+        );
+    })?;
+
+    Ok(())
+}
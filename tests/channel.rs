@@ -0,0 +1,34 @@
+use anyhow::Result;
+use fuelup::target_triple::TargetTriple;
+
+pub mod testcfg;
+use testcfg::FuelupState;
+
+#[test]
+fn fuelup_channel_show_unknown() -> Result<()> {
+    testcfg::setup(FuelupState::Empty, &|cfg| {
+        let output = cfg.fuelup(&["channel", "show", "beta-0"]);
+        assert_eq!(
+            output.stdout,
+            "Unknown name for toolchain: beta-0; expected one of latest, nightly, stable, beta-1, beta-2, beta-3\n"
+        );
+    })?;
+
+    Ok(())
+}
+
+#[test]
+fn fuelup_channel_diff_not_installed() -> Result<()> {
+    testcfg::setup(FuelupState::Empty, &|cfg| {
+        let output = cfg.fuelup(&["channel", "diff", "latest", "--installed"]);
+        assert_eq!(
+            output.stdout,
+            format!(
+                "Toolchain 'latest-{}' is not installed\n",
+                TargetTriple::from_host().unwrap()
+            )
+        );
+    })?;
+
+    Ok(())
+}
@@ -0,0 +1,134 @@
+use anyhow::Result;
+use fuelup::{
+    constants::{FUEL_TOOLCHAIN_LOCK_FILE, FUEL_TOOLCHAIN_TOML_FILE},
+    target_triple::TargetTriple,
+};
+use std::fs;
+
+pub mod testcfg;
+use testcfg::{FuelupState, TestCfg};
+
+fn setup_project(cfg: &TestCfg, channel: &str) {
+    let store = cfg.home.join(".fuelup").join("store");
+    for version in ["0.35.1", "0.35.3"] {
+        fs::create_dir_all(store.join(format!("forc-{version}"))).unwrap();
+        fs::write(store.join(format!("forc-{version}")).join("forc"), "").unwrap();
+    }
+
+    fs::write(
+        cfg.home.join(FUEL_TOOLCHAIN_TOML_FILE),
+        format!("[toolchain]\nchannel = \"{channel}\"\n\n[components]\nforc = \"^0.35\"\n"),
+    )
+    .unwrap();
+
+    let forc_target = TargetTriple::from_component("forc").unwrap();
+    let fuel_core_target = TargetTriple::from_component("fuel-core").unwrap();
+    fs::write(
+        cfg.home.join(FUEL_TOOLCHAIN_LOCK_FILE),
+        format!(
+            r#"[toolchain]
+channel = "latest-2023-01-09"
+
+[pkg.fuel-core]
+version = "0.17.1"
+
+[pkg.fuel-core.target.{fuel_core_target}]
+url = "https://github.com/FuelLabs/fuel-core/releases/download/v0.17.1/fuel-core-0.17.1-{fuel_core_target}.tar.gz"
+hash = "0000"
+
+[components.forc]
+version = "0.35.1"
+
+[components.forc.target.{forc_target}]
+url = "https://github.com/FuelLabs/sway/releases/download/v0.35.1/forc-binaries-{forc_target}.tar.gz"
+hash = "1111"
+"#
+        ),
+    )
+    .unwrap();
+}
+
+#[test]
+fn fuelup_lock_without_toolchain_toml() -> Result<()> {
+    testcfg::setup(FuelupState::LatestToolchainInstalled, &|cfg| {
+        let output = cfg.fuelup(&["lock"]);
+        assert_eq!(
+            output.stdout,
+            "No 'fuel-toolchain.toml' found in the current directory or its parents\n"
+        );
+        assert!(!cfg.home.join(FUEL_TOOLCHAIN_LOCK_FILE).exists());
+    })?;
+
+    Ok(())
+}
+
+#[test]
+fn fuelup_lock_malformed_toolchain_toml() -> Result<()> {
+    testcfg::setup(FuelupState::LatestToolchainInstalled, &|cfg| {
+        fs::write(cfg.home.join(FUEL_TOOLCHAIN_TOML_FILE), "[toolchain]\n").unwrap();
+
+        let output = cfg.fuelup(&["lock"]);
+        assert_eq!(
+            output.stdout,
+            "'[toolchain]' must declare either a 'channel' or the 'name' of a custom toolchain\n"
+        );
+        assert!(!cfg.home.join(FUEL_TOOLCHAIN_LOCK_FILE).exists());
+    })?;
+
+    Ok(())
+}
+
+#[test]
+fn fuelup_lock_up_to_date() -> Result<()> {
+    testcfg::setup(FuelupState::LatestToolchainInstalled, &|cfg| {
+        setup_project(cfg, "latest-2023-01-09");
+        let lock = fs::read_to_string(cfg.home.join(FUEL_TOOLCHAIN_LOCK_FILE)).unwrap();
+
+        let output = cfg.fuelup(&["lock"]);
+        assert_eq!(
+            output.stdout,
+            format!(
+                "{} is up to date\n",
+                cfg.home.join(FUEL_TOOLCHAIN_LOCK_FILE).display()
+            )
+        );
+        assert_eq!(
+            fs::read_to_string(cfg.home.join(FUEL_TOOLCHAIN_LOCK_FILE)).unwrap(),
+            lock
+        );
+    })?;
+
+    Ok(())
+}
+
+#[test]
+fn proxy_honors_lock() -> Result<()> {
+    testcfg::setup(FuelupState::LatestToolchainInstalled, &|cfg| {
+        setup_project(cfg, "latest-2023-01-09");
+
+        // The locked version is used over the highest installed one matching the requirement.
+        let output = cfg.fuelup(&["which", "forc", "--json"]);
+        let json: serde_json::Value = serde_json::from_str(&output.stdout).unwrap();
+        assert_eq!(json["reason"]["version"], "0.35.1");
+
+        // A lock for another channel is out of date, and ignored.
+        setup_project(cfg, "latest-2023-02-01");
+        let output = cfg.fuelup(&["which", "forc"]);
+        assert!(output.stdout.starts_with(
+            "warning: fuel-toolchain.lock is out of date with fuel-toolchain.toml; run 'fuelup lock' to update it\n"
+        ));
+        assert!(output
+            .stdout
+            .contains("reason: version 0.35.3 matching '^0.35' in "));
+
+        // Warnings go to stderr with --json, leaving stdout to the JSON alone.
+        let output = cfg.fuelup(&["which", "forc", "--json"]);
+        let json: serde_json::Value = serde_json::from_str(&output.stdout).unwrap();
+        assert_eq!(json["reason"]["version"], "0.35.3");
+        assert!(output.stderr.starts_with(
+            "warning: fuel-toolchain.lock is out of date with fuel-toolchain.toml; run 'fuelup lock' to update it\n"
+        ));
+    })?;
+
+    Ok(())
+}
@@ -0,0 +1,198 @@
+use anyhow::Result;
+use fuelup::constants::FUEL_TOOLCHAIN_TOML_FILE;
+use std::fs;
+
+pub mod testcfg;
+use testcfg::FuelupState;
+
+#[test]
+fn fuelup_override_check_valid() -> Result<()> {
+    testcfg::setup(FuelupState::AllInstalled, &|cfg| {
+        cfg.fuelup(&["toolchain", "new", "fuel-testnet"]);
+        fs::create_dir_all(cfg.home.join("sway").join("target").join("release")).unwrap();
+        fs::write(
+            cfg.home.join(FUEL_TOOLCHAIN_TOML_FILE),
+            "[toolchain]\nname = \"fuel-testnet\"\n\n[components]\nforc = { path = \"sway/target/release\" }\n",
+        )
+        .unwrap();
+
+        let output = cfg.fuelup(&["override", "check"]);
+        assert!(output.status.success());
+        assert_eq!(
+            output.stdout,
+            format!(
+                "{} is valid\n",
+                cfg.home.join(FUEL_TOOLCHAIN_TOML_FILE).display()
+            )
+        );
+    })?;
+
+    Ok(())
+}
+
+#[test]
+fn fuelup_override_check_problems() -> Result<()> {
+    testcfg::setup(FuelupState::AllInstalled, &|cfg| {
+        fs::write(
+            cfg.home.join(FUEL_TOOLCHAIN_TOML_FILE),
+            "[toolchain]\nname = \"fuel-testnet\"\n\n[components]\nforc = { path = \"sway/target/release\" }\nforc-foo = \"0.1.0\"\n",
+        )
+        .unwrap();
+
+        let output = cfg.fuelup(&["override", "check"]);
+        assert!(!output.status.success());
+        assert_eq!(
+            output.stdout,
+            format!(
+                "error: custom toolchain 'fuel-testnet' is not installed; you may create it using 'fuelup toolchain new fuel-testnet'
+error: the path declared for 'forc' does not exist: {}
+error: 'forc-foo' in [components] is not a known component
+3 problems found in {}
+",
+                cfg.home.join("sway/target/release").display(),
+                cfg.home.join(FUEL_TOOLCHAIN_TOML_FILE).display()
+            )
+        );
+    })?;
+
+    Ok(())
+}
+
+#[test]
+fn fuelup_override_check_invalid_toml() -> Result<()> {
+    testcfg::setup(FuelupState::AllInstalled, &|cfg| {
+        fs::write(
+            cfg.home.join(FUEL_TOOLCHAIN_TOML_FILE),
+            "[toolchain]\nchannel = \"beta-3\"\nname = \"fuel-testnet\"\n",
+        )
+        .unwrap();
+
+        let output = cfg.fuelup(&["override", "check"]);
+        assert!(!output.status.success());
+        assert!(output.stdout.starts_with(
+            "error: invalid fuel-toolchain.toml: '[toolchain]' declares both 'channel' and 'name'"
+        ));
+    })?;
+
+    Ok(())
+}
+
+#[test]
+fn fuelup_override_edit_preserves_formatting() -> Result<()> {
+    testcfg::setup(FuelupState::AllInstalled, &|cfg| {
+        let toml_path = cfg.home.join(FUEL_TOOLCHAIN_TOML_FILE);
+        fs::write(
+            &toml_path,
+            r#"# Shared by the whole team.
+[toolchain]
+channel = "beta-2" # the testnet we deploy to
+
+[components]
+# Needed for the new storage syntax.
+forc = "0.33.0"
+fuel-core = "0.15.1"
+"#,
+        )
+        .unwrap();
+
+        let output = cfg.fuelup(&["override", "set-channel", "beta-3"]);
+        assert_eq!(
+            output.stdout,
+            format!("Set channel to 'beta-3' in {}\n", toml_path.display())
+        );
+        let output = cfg.fuelup(&["override", "pin", "forc@^0.35"]);
+        assert_eq!(
+            output.stdout,
+            format!("Pinned forc to '^0.35' in {}\n", toml_path.display())
+        );
+        let output = cfg.fuelup(&["override", "unpin", "fuel-core"]);
+        assert_eq!(
+            output.stdout,
+            format!("Unpinned fuel-core in {}\n", toml_path.display())
+        );
+
+        assert_eq!(
+            fs::read_to_string(&toml_path).unwrap(),
+            r#"# Shared by the whole team.
+[toolchain]
+channel = "beta-3" # the testnet we deploy to
+
+[components]
+# Needed for the new storage syntax.
+forc = "^0.35"
+"#
+        );
+
+        let output = cfg.fuelup(&["override", "unpin", "fuel-core"]);
+        assert_eq!(
+            output.stdout,
+            format!("'fuel-core' is not pinned in {}\n", toml_path.display())
+        );
+    })?;
+
+    Ok(())
+}
+
+#[test]
+fn fuelup_override_edit_creates_file() -> Result<()> {
+    testcfg::setup(FuelupState::AllInstalled, &|cfg| {
+        let toml_path = cfg.home.join(FUEL_TOOLCHAIN_TOML_FILE);
+
+        // A pin alone does not make for a valid toolchain file.
+        let output = cfg.fuelup(&["override", "pin", "forc@0.35.0"]);
+        assert!(output.stdout.starts_with("Refusing to write an invalid"));
+        assert!(!toml_path.exists());
+
+        cfg.fuelup(&["override", "set-channel", "beta-3"]);
+        cfg.fuelup(&["override", "pin", "forc@0.35.0"]);
+        assert_eq!(
+            fs::read_to_string(&toml_path).unwrap(),
+            "[toolchain]\nchannel = \"beta-3\"\n\n[components]\nforc = \"0.35.0\"\n"
+        );
+
+        cfg.fuelup(&["override", "unpin", "forc"]);
+        assert_eq!(
+            fs::read_to_string(&toml_path).unwrap(),
+            "[toolchain]\nchannel = \"beta-3\"\n"
+        );
+    })?;
+
+    Ok(())
+}
+
+#[test]
+fn fuelup_override_named_channel_from_index() -> Result<()> {
+    testcfg::setup(FuelupState::AllInstalled, &|cfg| {
+        let toml_path = cfg.home.join(FUEL_TOOLCHAIN_TOML_FILE);
+
+        let output = cfg.fuelup(&["override", "set-channel", "testnet"]);
+        assert!(output
+            .stdout
+            .starts_with("Invalid channel 'testnet', expected one of"));
+        assert!(!toml_path.exists());
+
+        // A channel published within the index after this release of fuelup.
+        fs::write(
+            cfg.home.join(".fuelup").join("channels.toml"),
+            "[channel.testnet]\ndescription = \"The toolchain compatible with the testnet\"\n",
+        )
+        .unwrap();
+
+        let output = cfg.fuelup(&["override", "set-channel", "testnet"]);
+        assert_eq!(
+            output.stdout,
+            format!("Set channel to 'testnet' in {}\n", toml_path.display())
+        );
+        assert_eq!(
+            fs::read_to_string(&toml_path).unwrap(),
+            "[toolchain]\nchannel = \"testnet\"\n"
+        );
+
+        let output = cfg.fuelup(&["toolchain", "new", "testnet"]);
+        assert!(output.stderr.contains(
+            "Cannot use distributable toolchain name 'testnet' as a custom toolchain name"
+        ));
+    })?;
+
+    Ok(())
+}
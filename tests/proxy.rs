@@ -0,0 +1,406 @@
+use anyhow::Result;
+use fuelup::{constants::FUEL_TOOLCHAIN_TOML_FILE, fmt::format_toolchain_with_target};
+use std::fs;
+use std::os::unix::fs::PermissionsExt;
+use std::path::Path;
+
+pub mod testcfg;
+use testcfg::{FuelupState, DATE};
+
+#[test]
+fn proxy_default_toolchain() -> Result<()> {
+    testcfg::setup(FuelupState::LatestAndNightlyInstalled, &|cfg| {
+        let latest = format_toolchain_with_target("latest");
+        cfg.fake_executable(&latest, "forc");
+
+        let output = cfg.proxy("forc", &["build"]);
+        assert_eq!(output.stdout, format!("{latest} forc build\n"));
+    })?;
+
+    Ok(())
+}
+
+#[test]
+fn proxy_toolchain_selector() -> Result<()> {
+    testcfg::setup(FuelupState::LatestAndNightlyInstalled, &|cfg| {
+        let nightly = format_toolchain_with_target("nightly");
+        cfg.fake_executable(&nightly, "forc");
+        cfg.fake_executable(&nightly, "forc-fmt");
+
+        let output = cfg.proxy("forc", &["+nightly", "build", "--release"]);
+        assert_eq!(output.stdout, format!("{nightly} forc build --release\n"));
+
+        let output = cfg.proxy("forc", &["+nightly", "fmt", "--check"]);
+        assert_eq!(output.stdout, format!("{nightly} forc-fmt --check\n"));
+
+        let output = cfg.proxy("forc-fmt", &[&format!("+{nightly}")]);
+        assert_eq!(output.stdout, format!("{nightly} forc-fmt\n"));
+    })?;
+
+    Ok(())
+}
+
+#[test]
+fn proxy_toolchain_selector_custom() -> Result<()> {
+    testcfg::setup(FuelupState::LatestAndCustomInstalled, &|cfg| {
+        cfg.fake_executable("my-toolchain", "fuel-core");
+
+        let output = cfg.proxy("fuel-core", &["+my-toolchain", "run"]);
+        assert_eq!(output.stdout, "my-toolchain fuel-core run\n");
+
+        let output = cfg.proxy("fuel-core", &["+other-toolchain", "run"]);
+        assert_eq!(
+            output.stdout,
+            "toolchain 'other-toolchain' is not installed; you may create it using 'fuelup toolchain new other-toolchain'\n"
+        );
+    })?;
+
+    Ok(())
+}
+
+#[test]
+fn proxy_toolchain_from_env() -> Result<()> {
+    testcfg::setup(FuelupState::LatestAndNightlyInstalled, &|cfg| {
+        let nightly = format_toolchain_with_target("nightly");
+        cfg.fake_executable(&nightly, "forc");
+        cfg.proxy("forc", &[]);
+
+        // Proxied calls made from within `fuelup run` stay on the selected toolchain.
+        let output = cfg.fuelup(&[
+            "run",
+            "nightly",
+            "/bin/sh",
+            "-c",
+            "$HOME/.fuelup/bin/forc build",
+        ]);
+        assert_eq!(output.stdout, format!("{nightly} forc build\n"));
+    })?;
+
+    Ok(())
+}
+
+#[test]
+fn proxy_propagates_override_to_nested_calls() -> Result<()> {
+    testcfg::setup(FuelupState::AllInstalled, &|cfg| {
+        let nightly_date = format_toolchain_with_target(&format!("nightly-{DATE}"));
+        cfg.fake_executable(&nightly_date, "forc-fmt");
+        cfg.proxy("forc-fmt", &[]);
+
+        fs::write(
+            cfg.home.join(FUEL_TOOLCHAIN_TOML_FILE),
+            format!("[toolchain]\nchannel = \"nightly-{DATE}\"\n"),
+        )
+        .unwrap();
+
+        // A nested call from outside of the project still resolves through the override.
+        let forc = cfg.toolchain_bin_dir(&nightly_date).join("forc");
+        fs::write(
+            &forc,
+            "#!/bin/sh\ncd / && exec \"$HOME/.fuelup/bin/forc-fmt\" \"$@\"\n",
+        )
+        .unwrap();
+
+        let output = cfg.proxy("forc", &["--check"]);
+        assert_eq!(output.stdout, format!("{nightly_date} forc-fmt --check\n"));
+    })?;
+
+    Ok(())
+}
+
+#[test]
+fn proxy_exports_toolchain_env() -> Result<()> {
+    testcfg::setup(FuelupState::LatestToolchainInstalled, &|cfg| {
+        let latest = format_toolchain_with_target("latest");
+        fs::write(
+            cfg.toolchain_bin_dir(&latest).join("forc"),
+            "#!/bin/sh\necho $FUELUP_TOOLCHAIN $FUELUP_TOOLCHAIN_BIN_DIR $FUELUP_HOME\n",
+        )
+        .unwrap();
+
+        let output = cfg.proxy("forc", &[]);
+        assert_eq!(
+            output.stdout,
+            format!(
+                "{latest} {} {}\n",
+                cfg.toolchain_bin_dir(&latest).display(),
+                cfg.home.join(".fuelup").display()
+            )
+        );
+    })?;
+
+    Ok(())
+}
+
+#[test]
+fn proxy_unmanaged_plugin() -> Result<()> {
+    testcfg::setup(FuelupState::LatestAndCustomInstalled, &|cfg| {
+        let latest = format_toolchain_with_target("latest");
+        cfg.fake_executable(&latest, "forc");
+        cfg.fake_executable(&latest, "forc-foo");
+
+        let output = cfg.proxy("forc", &["foo", "--bar"]);
+        assert_eq!(output.stdout, format!("{latest} forc-foo --bar\n"));
+
+        // Subcommands without a matching plugin are left to forc.
+        let output = cfg.proxy("forc", &["baz"]);
+        assert_eq!(output.stdout, format!("{latest} forc baz\n"));
+
+        // Plugins of toolchains other than the resolved one are left out.
+        cfg.fake_executable("my-toolchain", "forc-qux");
+        let output = cfg.proxy("forc", &["qux"]);
+        assert_eq!(output.stdout, format!("{latest} forc qux\n"));
+    })?;
+
+    Ok(())
+}
+
+#[test]
+fn proxy_unmanaged_plugin_in_path() -> Result<()> {
+    testcfg::setup(FuelupState::LatestToolchainInstalled, &|cfg| {
+        let plugin = cfg.home.join(".local/bin/forc-foo");
+        fs::create_dir_all(plugin.parent().unwrap()).unwrap();
+        fs::write(&plugin, "#!/bin/sh\necho PATH forc-foo \"$@\"\n").unwrap();
+        fs::set_permissions(&plugin, fs::Permissions::from_mode(0o770)).unwrap();
+
+        let output = cfg.proxy("forc", &["foo", "--bar"]);
+        assert_eq!(output.stdout, "PATH forc-foo --bar\n");
+
+        // The toolchain it runs with is installed first, as for any other executable.
+        cfg.fuelup(&["set", "auto-install", "never"]);
+        let nightly = format_toolchain_with_target("nightly");
+        let output = cfg.proxy("forc", &["+nightly", "foo"]);
+        assert_eq!(
+            output.stdout,
+            format!("toolchain '{nightly}' is not installed; you may install it using 'fuelup toolchain install {nightly}'\n")
+        );
+
+        // The toolchain's own plugin takes precedence over the one found in PATH.
+        let latest = format_toolchain_with_target("latest");
+        cfg.fake_executable(&latest, "forc-foo");
+        let output = cfg.proxy("forc", &["foo"]);
+        assert_eq!(output.stdout, format!("{latest} forc-foo\n"));
+    })?;
+
+    Ok(())
+}
+
+#[test]
+fn proxy_auto_install_never() -> Result<()> {
+    testcfg::setup(FuelupState::LatestAndCustomInstalled, &|cfg| {
+        cfg.fuelup(&["set", "auto-install", "never"]);
+
+        let nightly = format_toolchain_with_target("nightly");
+        let output = cfg.proxy("forc", &["+nightly", "build"]);
+        assert_eq!(
+            output.stdout,
+            format!("toolchain '{nightly}' is not installed; you may install it using 'fuelup toolchain install {nightly}'\n")
+        );
+        assert!(!cfg.toolchain_bin_dir(&nightly).exists());
+
+        fs::remove_file(cfg.toolchain_bin_dir("my-toolchain").join("forc-fmt")).unwrap();
+        let output = cfg.proxy("forc-fmt", &["+my-toolchain"]);
+        assert_eq!(
+            output.stdout,
+            "component 'forc' in toolchain 'my-toolchain' is not installed; you may install it using 'fuelup default my-toolchain && fuelup component add forc'\n"
+        );
+    })?;
+
+    Ok(())
+}
+
+#[test]
+fn proxy_auto_install_prompt_non_interactive() -> Result<()> {
+    testcfg::setup(FuelupState::LatestAndCustomInstalled, &|cfg| {
+        cfg.fuelup(&["default", "my-toolchain"]);
+        cfg.fuelup(&["set", "auto-install", "prompt"]);
+        fs::remove_file(cfg.toolchain_bin_dir("my-toolchain").join("fuel-core")).unwrap();
+
+        // Without a terminal to prompt on, nothing is installed.
+        let output = cfg.proxy("fuel-core", &["run"]);
+        assert_eq!(
+            output.stdout,
+            "component 'fuel-core' in toolchain 'my-toolchain' is not installed; you may install it using 'fuelup component add fuel-core'\n"
+        );
+    })?;
+
+    Ok(())
+}
+
+#[test]
+fn proxy_project_dir() -> Result<()> {
+    testcfg::setup(FuelupState::AllInstalled, &|cfg| {
+        let nightly_date = format_toolchain_with_target(&format!("nightly-{DATE}"));
+        cfg.fake_executable(&nightly_date, "forc");
+        cfg.fake_executable(&nightly_date, "forc-lsp");
+
+        let project = cfg.home.join("project");
+        fs::create_dir(&project).unwrap();
+        fs::write(project.join("Forc.toml"), "").unwrap();
+        fs::write(
+            project.join(FUEL_TOOLCHAIN_TOML_FILE),
+            format!("[toolchain]\nchannel = \"nightly-{DATE}\"\n"),
+        )
+        .unwrap();
+
+        let output = cfg.proxy("forc", &["build", "--path", "project"]);
+        assert_eq!(
+            output.stdout,
+            format!("{nightly_date} forc build --path project\n")
+        );
+
+        let output = cfg.proxy("forc", &["build", "--manifest-path=project/Forc.toml"]);
+        assert_eq!(
+            output.stdout,
+            format!("{nightly_date} forc build --manifest-path=project/Forc.toml\n")
+        );
+
+        let output = cfg.proxy_with_env(
+            "forc-lsp",
+            &[],
+            &[("FUELUP_PROJECT_ROOT", project.to_str().unwrap())],
+        );
+        assert_eq!(output.stdout, format!("{nightly_date} forc-lsp\n"));
+
+        let output = cfg.fuelup(&["which", "forc-lsp", "--path", "project"]);
+        assert!(output
+            .stdout
+            .contains(&format!("toolchain: {nightly_date}\n")));
+
+        // A project that doesn't exist yet falls back to the working directory.
+        fs::rename(
+            project.join(FUEL_TOOLCHAIN_TOML_FILE),
+            cfg.home.join(FUEL_TOOLCHAIN_TOML_FILE),
+        )
+        .unwrap();
+        let output = cfg.proxy("forc", &["new", "--path", "new-project"]);
+        assert_eq!(
+            output.stdout,
+            format!("{nightly_date} forc new --path new-project\n")
+        );
+    })?;
+
+    Ok(())
+}
+
+#[test]
+fn proxy_component_path() -> Result<()> {
+    testcfg::setup(FuelupState::AllInstalled, &|cfg| {
+        let release_dir = cfg.home.join("sway").join("target").join("release");
+        fs::create_dir_all(&release_dir).unwrap();
+        fs::write(
+            release_dir.join("forc"),
+            "#!/bin/sh\necho local forc \"$@\"\n",
+        )
+        .unwrap();
+        fs::set_permissions(release_dir.join("forc"), fs::Permissions::from_mode(0o770)).unwrap();
+        fs::write(
+            cfg.home.join(FUEL_TOOLCHAIN_TOML_FILE),
+            format!("[toolchain]\nchannel = \"nightly-{DATE}\"\n\n[components]\nforc = {{ path = \"sway/target/release\" }}\n"),
+        )
+        .unwrap();
+
+        let output = cfg.proxy("forc", &["build"]);
+        assert_eq!(output.stdout, "local forc build\n");
+
+        let output = cfg.proxy("forc-fmt", &[]);
+        assert_eq!(
+            output.stdout,
+            format!(
+                "'forc-fmt' not found in {}, the path declared for forc; you may need to build it first\n",
+                release_dir.display()
+            )
+        );
+    })?;
+
+    Ok(())
+}
+
+#[test]
+fn proxy_component_pinned_not_installed() -> Result<()> {
+    testcfg::setup(FuelupState::AllInstalled, &|cfg| {
+        cfg.fuelup(&["set", "auto-install", "never"]);
+        fs::write(
+            cfg.home.join(FUEL_TOOLCHAIN_TOML_FILE),
+            format!("[toolchain]\nchannel = \"nightly-{DATE}\"\n\n[components]\nfuel-core = \"0.17.3\"\n"),
+        )
+        .unwrap();
+
+        let output = cfg.proxy("fuel-core", &["run"]);
+        assert_eq!(
+            output.stdout,
+            "fuel-core 0.17.3 is not installed; you may install it using 'fuelup toolchain install --from-file'\n"
+        );
+    })?;
+
+    Ok(())
+}
+
+#[test]
+fn proxy_component_git() -> Result<()> {
+    testcfg::setup(FuelupState::AllInstalled, &|cfg| {
+        cfg.fuelup(&["set", "auto-install", "never"]);
+        fs::write(
+            cfg.home.join(FUEL_TOOLCHAIN_TOML_FILE),
+            format!("[toolchain]\nchannel = \"nightly-{DATE}\"\n\n[components]\nfuel-core = {{ git = \"https://github.com/FuelLabs/fuel-core\", rev = \"abc123\" }}\n"),
+        )
+        .unwrap();
+
+        // The checkout is keyed by a hash of the repository url as well as the revision.
+        let output = cfg.fuelup(&["which", "fuel-core"]);
+        let (path, _) = output.stdout.split_once(" (not installed)\n").unwrap();
+        let path = Path::new(path)
+            .strip_prefix(cfg.home.join(".fuelup/git"))
+            .unwrap();
+        let checkout = path
+            .components()
+            .next()
+            .unwrap()
+            .as_os_str()
+            .to_string_lossy();
+        assert!(checkout.starts_with("fuel-core-"));
+        assert!(checkout.ends_with("-abc123"));
+        assert_eq!(
+            path.strip_prefix(checkout.as_ref()).unwrap(),
+            Path::new("target/release/fuel-core")
+        );
+        assert!(output.stdout.contains(
+            "reason: built from https://github.com/FuelLabs/fuel-core#abc123 as declared in "
+        ));
+
+        let output = cfg.proxy("fuel-core", &["run"]);
+        assert_eq!(
+            output.stdout,
+            "fuel-core from https://github.com/FuelLabs/fuel-core#abc123 is not installed; you may install it using 'fuelup toolchain install --from-file'\n"
+        );
+    })?;
+
+    Ok(())
+}
+
+#[test]
+fn proxy_custom_toolchain_override() -> Result<()> {
+    testcfg::setup(FuelupState::AllInstalled, &|cfg| {
+        fs::write(
+            cfg.home.join(FUEL_TOOLCHAIN_TOML_FILE),
+            "[toolchain]\nname = \"fuel-testnet\"\n",
+        )
+        .unwrap();
+
+        let output = cfg.proxy("forc", &["build"]);
+        assert_eq!(
+            output.stdout,
+            format!(
+                "toolchain 'fuel-testnet' declared in {} is not installed; you may create it using 'fuelup toolchain new fuel-testnet'\n",
+                cfg.home.join(FUEL_TOOLCHAIN_TOML_FILE).display()
+            )
+        );
+
+        cfg.fuelup(&["toolchain", "new", "fuel-testnet"]);
+        cfg.fake_executable("fuel-testnet", "forc");
+
+        let output = cfg.proxy("forc", &["build"]);
+        assert_eq!(output.stdout, "fuel-testnet forc build\n");
+    })?;
+
+    Ok(())
+}
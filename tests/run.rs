@@ -0,0 +1,63 @@
+use anyhow::Result;
+use fuelup::fmt::format_toolchain_with_target;
+
+pub mod testcfg;
+use testcfg::FuelupState;
+
+#[test]
+fn fuelup_run() -> Result<()> {
+    testcfg::setup(FuelupState::LatestAndNightlyInstalled, &|cfg| {
+        let nightly = format_toolchain_with_target("nightly");
+        cfg.fake_executable(&nightly, "forc");
+
+        let output = cfg.fuelup(&["run", "nightly", "forc", "build", "--release"]);
+        assert_eq!(output.stdout, format!("{nightly} forc build --release\n"));
+        // The default toolchain is left untouched.
+        assert_eq!(
+            cfg.default_toolchain(),
+            Some(format_toolchain_with_target("latest"))
+        );
+    })?;
+
+    Ok(())
+}
+
+#[test]
+fn fuelup_run_sets_toolchain_env() -> Result<()> {
+    testcfg::setup(FuelupState::LatestAndCustomInstalled, &|cfg| {
+        let output = cfg.fuelup(&[
+            "run",
+            "my-toolchain",
+            "/bin/sh",
+            "-c",
+            "echo $FUELUP_TOOLCHAIN",
+        ]);
+        assert_eq!(output.stdout, "my-toolchain\n");
+    })?;
+
+    Ok(())
+}
+
+#[test]
+fn fuelup_run_propagates_exit_code() -> Result<()> {
+    testcfg::setup(FuelupState::LatestToolchainInstalled, &|cfg| {
+        let output = cfg.fuelup(&["run", "latest", "/bin/sh", "-c", "exit 3"]);
+        assert_eq!(output.status.code(), Some(3));
+    })?;
+
+    Ok(())
+}
+
+#[test]
+fn fuelup_run_command_not_found() -> Result<()> {
+    testcfg::setup(FuelupState::LatestToolchainInstalled, &|cfg| {
+        let latest = format_toolchain_with_target("latest");
+        let output = cfg.fuelup(&["run", "latest", "forc-unknown"]);
+        assert_eq!(
+            output.stdout,
+            format!("command 'forc-unknown' not found in toolchain '{latest}' or PATH\n")
+        );
+    })?;
+
+    Ok(())
+}
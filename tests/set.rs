@@ -0,0 +1,52 @@
+use anyhow::Result;
+use fuelup::settings::AutoInstall;
+
+pub mod testcfg;
+use testcfg::FuelupState;
+
+#[test]
+fn fuelup_set_auto_install() -> Result<()> {
+    testcfg::setup(FuelupState::LatestToolchainInstalled, &|cfg| {
+        let output = cfg.fuelup(&["set", "auto-install"]);
+        assert_eq!(output.stdout, "always\n");
+
+        let output = cfg.fuelup(&["set", "auto-install", "never"]);
+        assert_eq!(output.stdout, "auto-install policy set to 'never'\n");
+        assert_eq!(
+            cfg.settings_file().with(|s| Ok(s.auto_install)).unwrap(),
+            Some(AutoInstall::Never)
+        );
+
+        let output = cfg.fuelup(&["set", "auto-install", "sometimes"]);
+        assert!(output
+            .stderr
+            .contains("Unknown auto-install policy 'sometimes'"));
+    })?;
+
+    Ok(())
+}
+
+#[test]
+fn fuelup_set_profile() -> Result<()> {
+    testcfg::setup(FuelupState::LatestToolchainInstalled, &|cfg| {
+        let output = cfg.fuelup(&["set", "profile"]);
+        assert_eq!(output.stdout, "default\n");
+
+        let output = cfg.fuelup(&["set", "profile", "minimal"]);
+        assert_eq!(output.stdout, "profile set to 'minimal'\n");
+        assert_eq!(
+            cfg.settings_file().with(|s| Ok(s.profile.clone())).unwrap(),
+            Some("minimal".to_string())
+        );
+
+        let output = cfg.fuelup(&["set", "profile", "everything"]);
+        assert_eq!(
+            output.stdout,
+            "Unknown profile 'everything' [possible values: complete, default, minimal]\n"
+        );
+        let output = cfg.fuelup(&["set", "profile"]);
+        assert_eq!(output.stdout, "minimal\n");
+    })?;
+
+    Ok(())
+}
@@ -93,3 +93,25 @@ beta-1-{target} (override), path: {}
     })?;
     Ok(())
 }
+
+#[test]
+fn fuelup_show_unmanaged_plugins() -> Result<()> {
+    testcfg::setup(FuelupState::LatestToolchainInstalled, &|cfg| {
+        let latest = format!("latest-{}", TargetTriple::from_host().unwrap());
+        cfg.fake_executable(&latest, "forc-foo");
+
+        let stdout = cfg.fuelup(&["show"]).stdout;
+        let expected_stdout = &format!(
+            "  unmanaged plugins\n    - forc-foo : {}\n",
+            cfg.toolchain_bin_dir(&latest).join("forc-foo").display()
+        );
+        assert!(stdout.contains(expected_stdout));
+
+        let stdout = cfg.fuelup(&["component", "list"]).stdout;
+        assert!(stdout.contains(&format!(
+            "Unmanaged plugins:\n  forc-foo ({})\n",
+            cfg.toolchain_bin_dir(&latest).join("forc-foo").display()
+        )));
+    })?;
+    Ok(())
+}
@@ -3,7 +3,7 @@ use fuelup::constants::FUEL_TOOLCHAIN_TOML_FILE;
 use fuelup::settings::SettingsFile;
 use fuelup::target_triple::TargetTriple;
 use fuelup::toolchain_override::{self, OverrideCfg, ToolchainCfg, ToolchainOverride};
-use std::os::unix::fs::OpenOptionsExt;
+use std::os::unix::fs::{OpenOptionsExt, PermissionsExt};
 use std::str::FromStr;
 use std::{
     env, fs,
@@ -85,8 +85,56 @@ impl TestCfg {
     }
 
     pub fn fuelup(&mut self, args: &[&str]) -> TestOutput {
-        let output = Command::new(&self.fuelup_path)
+        self.exec(&self.fuelup_path.clone(), args, &[])
+    }
+
+    /// Runs fuelup in proxy mode, eg. as `forc`, by linking it under the name of the proxy.
+    pub fn proxy(&mut self, proc_name: &str, args: &[&str]) -> TestOutput {
+        self.proxy_with_env(proc_name, args, &[])
+    }
+
+    /// Like `proxy()`, with additional environment variables set.
+    pub fn proxy_with_env(
+        &mut self,
+        proc_name: &str,
+        args: &[&str],
+        envs: &[(&str, &str)],
+    ) -> TestOutput {
+        let proxy_path = self.fuelup_path.with_file_name(proc_name);
+        if !proxy_path.exists() {
+            fs::hard_link(&self.fuelup_path, &proxy_path).expect("Failed to link proxy");
+        }
+        self.exec(&proxy_path, args, envs)
+    }
+
+    /// Caches the revisions of 'latest' from 'tests/revision-index-latest-example.toml', as if
+    /// fetched before.
+    pub fn cache_revision_index(&self) {
+        let index_dir = self.home.join(".fuelup").join("channels").join("latest");
+        fs::create_dir_all(&index_dir).expect("Failed to create channels dir");
+        fs::copy(
+            Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/revision-index-latest-example.toml"),
+            index_dir.join("index.toml"),
+        )
+        .expect("Failed to cache revision index");
+    }
+
+    /// Writes a fake executable into a toolchain that echoes its name along with its args.
+    pub fn fake_executable(&self, toolchain: &str, name: &str) {
+        let path = self.toolchain_bin_dir(toolchain).join(name);
+        fs::write(
+            &path,
+            format!("#!/bin/sh\necho {toolchain} {name} \"$@\"\n"),
+        )
+        .expect("Failed to write fake executable");
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o770))
+            .expect("Failed to set permissions");
+    }
+
+    fn exec(&mut self, path: &Path, args: &[&str], envs: &[(&str, &str)]) -> TestOutput {
+        let output = Command::new(path)
             .args(args)
+            .envs(envs.iter().copied())
             .current_dir(&self.home)
             .env("HOME", &self.home)
             .env("CARGO_HOME", self.home.join(".cargo").to_str().unwrap())
@@ -115,6 +163,7 @@ impl TestCfg {
 fn create_fuel_executable(path: &Path) -> std::io::Result<()> {
     fs::OpenOptions::new()
         .create(true)
+        .truncate(true)
         .write(true)
         .mode(0o770)
         .open(path)?;
@@ -249,7 +298,8 @@ pub fn setup(state: FuelupState, f: &dyn Fn(&mut TestCfg)) -> Result<()> {
             setup_override_file(ToolchainOverride {
                 cfg: OverrideCfg::new(
                     ToolchainCfg {
-                        channel: toolchain_override::Channel::from_str("beta-1").unwrap(),
+                        channel: Some(toolchain_override::Channel::from_str("beta-1").unwrap()),
+                        name: None,
                     },
                     None,
                 ),
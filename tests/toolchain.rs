@@ -1,5 +1,10 @@
 use anyhow::Result;
-use fuelup::{channel, fmt::format_toolchain_with_target, target_triple::TargetTriple};
+use fuelup::{
+    channel, constants::FUEL_TOOLCHAIN_TOML_FILE, fmt::format_toolchain_with_target,
+    target_triple::TargetTriple,
+};
+use std::fs;
+use std::os::unix::fs::PermissionsExt;
 
 pub mod testcfg;
 use testcfg::{FuelupState, ALL_BINS, DATE};
@@ -49,6 +54,29 @@ fn fuelup_toolchain_install_nightly() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn fuelup_toolchain_install_profile_minimal() -> Result<()> {
+    testcfg::setup(FuelupState::Empty, &|cfg| {
+        let output = cfg.fuelup(&["toolchain", "install", "latest", "--profile", "minimal"]);
+        assert!(output.status.success());
+
+        let latest = format_toolchain_with_target("latest");
+        let selection =
+            fs::read_to_string(cfg.toolchains_dir().join(&latest).join("selection.toml")).unwrap();
+        assert!(selection.contains("\"fuel-core\""));
+        assert!(!selection.contains("forc-wallet"));
+
+        // Neither updating nor checking the toolchain brings back the default profile.
+        let output = cfg.fuelup(&["update"]);
+        assert!(output.status.success());
+        assert!(!cfg.toolchain_bin_dir(&latest).join("forc-wallet").exists());
+        let output = cfg.fuelup(&["check"]);
+        assert!(!output.stdout.contains("forc-wallet"));
+    })?;
+
+    Ok(())
+}
+
 #[test]
 fn fuelup_toolchain_install_nightly_date() -> Result<()> {
     testcfg::setup(FuelupState::Empty, &|cfg| {
@@ -185,3 +213,299 @@ fn fuelup_toolchain_new_disallowed_with_target() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn fuelup_toolchain_export_custom() -> Result<()> {
+    testcfg::setup(FuelupState::Empty, &|cfg| {
+        cfg.fuelup(&["toolchain", "new", "empty"]);
+        let output = cfg.fuelup(&["toolchain", "export", "empty"]);
+        assert_eq!(
+            output.stdout,
+            "Could not find a revision containing the components of custom toolchain 'empty'; use --channel <channel> to pin its components on top of a channel\n"
+        );
+
+        let name = "my-toolchain";
+        cfg.fuelup(&["toolchain", "new", name]);
+        let bin_dir = cfg.toolchain_bin_dir(name);
+
+        // forc is installed from the store, while fuel-core was copied in by hand.
+        let store_dir = cfg.home.join(".fuelup/store/forc-0.35.1");
+        fs::create_dir_all(&store_dir).unwrap();
+        for exe in [
+            "forc",
+            "forc-fmt",
+            "forc-lsp",
+            "forc-doc",
+            "forc-deploy",
+            "forc-run",
+        ] {
+            fs::write(store_dir.join(exe), "").unwrap();
+            fs::hard_link(store_dir.join(exe), bin_dir.join(exe)).unwrap();
+        }
+        fs::write(
+            bin_dir.join("fuel-core"),
+            "#!/bin/sh\necho fuel-core 0.17.1\n",
+        )
+        .unwrap();
+        fs::set_permissions(bin_dir.join("fuel-core"), fs::Permissions::from_mode(0o770)).unwrap();
+
+        // Both versions were published together in 'latest-2023-02-10'.
+        cfg.cache_revision_index();
+        let toml_path = cfg.home.join(FUEL_TOOLCHAIN_TOML_FILE);
+        let output = cfg.fuelup(&["toolchain", "export", name]);
+        assert_eq!(
+            output.stdout,
+            format!(
+                "Exported 'my-toolchain' to {}\n  forc 0.35.1\n  fuel-core 0.17.1\n",
+                toml_path.display()
+            )
+        );
+        assert_eq!(
+            fs::read_to_string(&toml_path).unwrap(),
+            "[toolchain]\nchannel = \"latest-2023-02-10\"\n\n[components]\nforc = \"0.35.1\"\nfuel-core = \"0.17.1\"\n"
+        );
+
+        let output = cfg.fuelup(&["toolchain", "export", name, "--channel", "beta-3"]);
+        assert_eq!(
+            output.stdout,
+            format!(
+                "{} already exists; use --force to overwrite it\n",
+                toml_path.display()
+            )
+        );
+
+        cfg.fuelup(&[
+            "toolchain",
+            "export",
+            name,
+            "--channel",
+            "beta-3",
+            "--force",
+        ]);
+        assert_eq!(
+            fs::read_to_string(&toml_path).unwrap(),
+            "[toolchain]\nchannel = \"beta-3\"\n\n[components]\nforc = \"0.35.1\"\nfuel-core = \"0.17.1\"\n"
+        );
+    })?;
+
+    Ok(())
+}
+
+#[test]
+fn fuelup_toolchain_export_dist() -> Result<()> {
+    testcfg::setup(FuelupState::AllInstalled, &|cfg| {
+        let toml_path = cfg.home.join(FUEL_TOOLCHAIN_TOML_FILE);
+        cfg.fuelup(&["toolchain", "export", &format!("nightly-{DATE}")]);
+        assert_eq!(
+            fs::read_to_string(&toml_path).unwrap(),
+            format!("[toolchain]\nchannel = \"nightly-{DATE}\"\n")
+        );
+
+        let latest = format_toolchain_with_target("latest");
+        let output = cfg.fuelup(&["toolchain", "export", "--force"]);
+        assert_eq!(
+            output.stdout,
+            format!("Could not find the revision of 'latest' that '{latest}' was installed from; use --channel <channel> to pin its components on top of a dated channel\n")
+        );
+
+        // The hash recorded at installation identifies the revision it came from.
+        cfg.cache_revision_index();
+        let hashes_dir = cfg.home.join(".fuelup").join("hashes");
+        fs::create_dir_all(&hashes_dir).unwrap();
+        fs::write(hashes_dir.join(&latest), "bbbb").unwrap();
+        cfg.fuelup(&["toolchain", "export", "--force"]);
+        assert_eq!(
+            fs::read_to_string(&toml_path).unwrap(),
+            "[toolchain]\nchannel = \"latest-2023-01-27\"\n"
+        );
+    })?;
+
+    Ok(())
+}
+
+#[test]
+fn fuelup_toolchain_install_from_file() -> Result<()> {
+    testcfg::setup(FuelupState::Empty, &|cfg| {
+        cfg.fuelup(&["toolchain", "new", "fuel-testnet"]);
+        let store_dir = cfg.home.join(".fuelup/store/forc-0.35.3");
+        fs::create_dir_all(&store_dir).unwrap();
+        fs::write(store_dir.join("forc"), "").unwrap();
+
+        let toml_path = cfg.home.join(FUEL_TOOLCHAIN_TOML_FILE);
+        fs::write(
+            &toml_path,
+            "[toolchain]\nname = \"fuel-testnet\"\n\n[components]\nforc = \"0.35.3\"\nfuel-core = { path = \"fuel-core/target/release\" }\n",
+        )
+        .unwrap();
+
+        let output = cfg.fuelup(&["toolchain", "install", "--from-file"]);
+        assert_eq!(
+            output.stdout,
+            format!(
+                "\nInstalled 'fuel-testnet' as declared in {}\n- forc 0.35.3 (pinned)\n- fuel-core from {}\n",
+                toml_path.display(),
+                cfg.home.join("fuel-core/target/release").display()
+            )
+        );
+        // Proxies for the pinned components' executables are linked as well.
+        for exe in ["forc", "forc-deploy", "fuel-core"] {
+            assert!(cfg.home.join(".fuelup/bin").join(exe).is_file());
+        }
+
+        fs::write(&toml_path, "[toolchain]\nname = \"fuel-devnet\"\n").unwrap();
+        let output = cfg.fuelup(&["toolchain", "install", "--from-file"]);
+        assert_eq!(
+            output.stdout,
+            format!(
+                "toolchain 'fuel-devnet' declared in {} is not installed; you may create it using 'fuelup toolchain new fuel-devnet'\n",
+                toml_path.display()
+            )
+        );
+    })?;
+
+    Ok(())
+}
+
+#[test]
+fn fuelup_toolchain_list_revisions_offline() -> Result<()> {
+    testcfg::setup(FuelupState::Empty, &|cfg| {
+        let output = cfg.fuelup(&["toolchain", "list-revisions", "--offline"]);
+        assert_eq!(
+            output.stdout,
+            "The revisions of 'latest' have not been fetched yet; run without --offline first\n"
+        );
+
+        cfg.cache_revision_index();
+
+        let output = cfg.fuelup(&[
+            "toolchain",
+            "list-revisions",
+            "latest",
+            "--since",
+            "2023-01-10",
+            "--offline",
+        ]);
+        assert_eq!(
+            output.stdout,
+            "'latest' revisions available:
+latest-2023-02-10
+  forc 0.35.1
+  fuel-core 0.17.1
+latest-2023-01-27
+  forc 0.35.0
+  fuel-core 0.17.1
+
+for revisions published before the revision index, visit: https://github.com/FuelLabs/fuelup/tree/gh-pages/channels/latest
+"
+        );
+
+        let output = cfg.fuelup(&[
+            "toolchain",
+            "list-revisions",
+            "--until",
+            "2023-01-01",
+            "--offline",
+        ]);
+        assert_eq!(
+            output.stdout,
+            "No 'latest' revisions were published within the given dates\n"
+        );
+
+        let output = cfg.fuelup(&["toolchain", "list-revisions", "beta-3", "--offline"]);
+        assert_eq!(
+            output.stdout,
+            "'beta-3' is not published by date and has no revisions; expected one of latest, nightly, stable\n"
+        );
+    })?;
+
+    Ok(())
+}
+
+#[test]
+fn fuelup_toolchain_install_unknown_profile() -> Result<()> {
+    testcfg::setup(FuelupState::Empty, &|cfg| {
+        let output = cfg.fuelup(&["toolchain", "install", "latest", "--profile", "everything"]);
+        assert_eq!(
+            output.stdout,
+            "Unknown profile 'everything' [possible values: complete, default, minimal]\n"
+        );
+    })?;
+
+    Ok(())
+}
+
+#[test]
+fn fuelup_toolchain_install_unknown_component() -> Result<()> {
+    testcfg::setup(FuelupState::Empty, &|cfg| {
+        for flag in ["--component", "--exclude"] {
+            let output = cfg.fuelup(&["toolchain", "install", "latest", flag, "forc-foo"]);
+            assert_eq!(
+                output.stdout,
+                "'forc-foo' is not a published component; expected one of forc, forc-explore, forc-index, forc-wallet, fuel-core, fuel-indexer\n"
+            );
+        }
+
+        let output = cfg.fuelup(&[
+            "toolchain",
+            "install",
+            "latest",
+            "--component",
+            "forc",
+            "--profile",
+            "minimal",
+        ]);
+        assert!(output.stderr.contains("cannot be used with"));
+    })?;
+
+    Ok(())
+}
+
+#[test]
+fn fuelup_toolchain_install_as_of_undated_channel() -> Result<()> {
+    testcfg::setup(FuelupState::Empty, &|cfg| {
+        for name in ["beta-3", "latest-2023-01-27"] {
+            let output = cfg.fuelup(&["toolchain", "install", name, "--as-of", "2023-02-01"]);
+            assert_eq!(
+                output.stdout,
+                "--as-of only applies to channels published by date; expected one of latest, nightly, stable\n"
+            );
+        }
+    })?;
+
+    Ok(())
+}
+
+#[test]
+fn fuelup_toolchain_search_offline() -> Result<()> {
+    testcfg::setup(FuelupState::Empty, &|cfg| {
+        cfg.cache_revision_index();
+
+        let output = cfg.fuelup(&["toolchain", "search", "fuel-core@0.17.1", "--offline"]);
+        assert_eq!(
+            output.stdout,
+            "'latest' revisions containing fuel-core 0.17.1:
+latest-2023-02-10
+  forc 0.35.1
+  fuel-core 0.17.1
+latest-2023-01-27
+  forc 0.35.0
+  fuel-core 0.17.1
+
+"
+        );
+
+        let output = cfg.fuelup(&[
+            "toolchain",
+            "search",
+            "forc@0.35.0",
+            "fuel-core@0.15.1",
+            "--offline",
+        ]);
+        assert_eq!(
+            output.stdout,
+            "No 'latest' revision since 2023-01-09 contains forc 0.35.0, fuel-core 0.15.1; earlier revisions are not covered by the revision index\n"
+        );
+    })?;
+
+    Ok(())
+}
@@ -0,0 +1,93 @@
+use anyhow::Result;
+use fuelup::{constants::FUEL_TOOLCHAIN_TOML_FILE, fmt::format_toolchain_with_target};
+use std::fs;
+
+pub mod testcfg;
+use testcfg::FuelupState;
+
+#[test]
+fn fuelup_which_default() -> Result<()> {
+    testcfg::setup(FuelupState::LatestToolchainInstalled, &|cfg| {
+        let latest = format_toolchain_with_target("latest");
+        let output = cfg.fuelup(&["which", "forc"]);
+
+        let expected_stdout = format!(
+            "{}\ntoolchain: {latest}\nreason: default toolchain\n",
+            cfg.toolchain_bin_dir(&latest).join("forc").display()
+        );
+        assert_eq!(output.stdout, expected_stdout);
+    })?;
+
+    Ok(())
+}
+
+#[test]
+fn fuelup_which_override() -> Result<()> {
+    testcfg::setup(FuelupState::LatestAndNightlyWithBetaOverride, &|cfg| {
+        let beta_1 = format_toolchain_with_target("beta-1");
+        let output = cfg.fuelup(&["which", "forc-fmt"]);
+
+        let expected_stdout = format!(
+            "{} (not installed)\ntoolchain: {beta_1}\nreason: overridden by {}\n",
+            cfg.toolchain_bin_dir(&beta_1).join("forc-fmt").display(),
+            cfg.home.join(FUEL_TOOLCHAIN_TOML_FILE).display()
+        );
+        assert_eq!(output.stdout, expected_stdout);
+        // Nothing is installed when resolving.
+        assert!(!cfg.toolchains_dir().join(&beta_1).exists());
+    })?;
+
+    Ok(())
+}
+
+#[test]
+fn fuelup_which_json() -> Result<()> {
+    testcfg::setup(FuelupState::LatestToolchainInstalled, &|cfg| {
+        let latest = format_toolchain_with_target("latest");
+        let output = cfg.fuelup(&["which", "fuel-core", "--json"]);
+
+        let json: serde_json::Value = serde_json::from_str(&output.stdout).unwrap();
+        assert_eq!(
+            json["path"],
+            cfg.toolchain_bin_dir(&latest)
+                .join("fuel-core")
+                .display()
+                .to_string()
+        );
+        assert_eq!(json["toolchain"], latest);
+        assert_eq!(json["reason"]["kind"], "default");
+        assert_eq!(json["installed"], true);
+    })?;
+
+    Ok(())
+}
+
+#[test]
+fn fuelup_which_version_requirement() -> Result<()> {
+    testcfg::setup(FuelupState::LatestToolchainInstalled, &|cfg| {
+        let store = cfg.home.join(".fuelup").join("store");
+        for version in ["0.35.1", "0.35.3", "0.36.0"] {
+            fs::create_dir_all(store.join(format!("forc-{version}"))).unwrap();
+            fs::write(store.join(format!("forc-{version}")).join("forc"), "").unwrap();
+        }
+        fs::write(
+            cfg.home.join(FUEL_TOOLCHAIN_TOML_FILE),
+            "[toolchain]\nchannel = \"latest-2023-01-09\"\n\n[components]\nforc = \"^0.35\"\n",
+        )
+        .unwrap();
+
+        // The highest installed version matching the requirement is selected, without querying
+        // releases, and nothing but the JSON is printed.
+        let output = cfg.fuelup(&["which", "forc", "--json"]);
+        let json: serde_json::Value = serde_json::from_str(&output.stdout).unwrap();
+        assert_eq!(
+            json["path"],
+            store.join("forc-0.35.3").join("forc").display().to_string()
+        );
+        assert_eq!(json["reason"]["kind"], "component_pin");
+        assert_eq!(json["reason"]["version"], "0.35.3");
+        assert_eq!(json["reason"]["requirement"], "^0.35");
+    })?;
+
+    Ok(())
+}